@@ -18,6 +18,20 @@ fn insert(b: &mut test::Bencher, num: u32) {
     })
 }
 
+fn insert_many(b: &mut test::Bencher, num: u32) {
+    b.iter(|| {
+        let mut map = LinearMap::new();
+        map.insert_many((0..num).map(|i| (i, i)));
+    })
+}
+
+fn insert_many_hashed(b: &mut test::Bencher, num: u32) {
+    b.iter(|| {
+        let mut map = LinearMap::new();
+        map.insert_many_hashed((0..num).map(|i| (i, i)));
+    })
+}
+
 fn remove_insert(b: &mut test::Bencher, num: u32) {
     b.iter(|| {
         let mut map = LinearMap::new();
@@ -79,6 +93,32 @@ fn bench_insert_big(b: &mut test::Bencher) {
     insert(b, BIG);
 }
 
+#[bench]
+fn bench_insert_many_small(b: &mut test::Bencher) {
+    insert_many(b, SMALL);
+}
+#[bench]
+fn bench_insert_many_medium(b: &mut test::Bencher) {
+    insert_many(b, MEDIUM);
+}
+#[bench]
+fn bench_insert_many_big(b: &mut test::Bencher) {
+    insert_many(b, BIG);
+}
+
+#[bench]
+fn bench_insert_many_hashed_small(b: &mut test::Bencher) {
+    insert_many_hashed(b, SMALL);
+}
+#[bench]
+fn bench_insert_many_hashed_medium(b: &mut test::Bencher) {
+    insert_many_hashed(b, MEDIUM);
+}
+#[bench]
+fn bench_insert_many_hashed_big(b: &mut test::Bencher) {
+    insert_many_hashed(b, BIG);
+}
+
 #[bench]
 fn bench_remove_insert_small(b: &mut test::Bencher) {
     remove_insert(b, SMALL);
@@ -0,0 +1,500 @@
+//! A multiset implemented by searching linearly in a vector.
+//!
+//! See the [`LinearBag`](struct.LinearBag.html) type for details.
+
+use std::borrow::Borrow;
+use std::cmp::Reverse;
+use std::fmt;
+use std::iter::{FromIterator, FusedIterator};
+use std::ops::{Add, BitAnd, BitOr, Sub};
+
+use super::{Iter as MapIter, LinearMap};
+
+/// A multiset implemented by searching linearly in a vector, storing each distinct element
+/// along with the number of times it occurs.
+///
+/// `LinearBag` uses the same storage as a `LinearMap<T, usize>` and never stores an element
+/// with a count of zero.
+///
+/// # Examples
+///
+/// ```
+/// use linear_map::bag::LinearBag;
+///
+/// let mut tally: LinearBag<_> = "abracadabra".chars().collect();
+///
+/// assert_eq!(tally.count(&'a'), 5);
+/// assert_eq!(tally.len(), 5);
+/// assert_eq!(tally.total(), 11);
+/// assert_eq!(tally.most_common(2), [(&'a', 5), (&'b', 2)]);
+///
+/// tally.remove_one(&'a');
+/// tally.add_n('z', 3);
+/// assert_eq!(tally.count(&'a'), 4);
+/// assert_eq!(tally.count(&'z'), 3);
+/// ```
+#[derive(Clone)]
+pub struct LinearBag<T> {
+    map: LinearMap<T, usize>,
+}
+
+impl<T: Eq> LinearBag<T> {
+    /// Creates an empty bag.
+    pub fn new() -> Self {
+        LinearBag {
+            map: LinearMap::new(),
+        }
+    }
+
+    /// Creates an empty bag with room for the given number of distinct elements.
+    pub fn with_capacity(capacity: usize) -> Self {
+        LinearBag {
+            map: LinearMap::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the number of distinct elements in the bag.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Checks if the bag is empty.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns the number of elements in the bag, counted with multiplicity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::bag::LinearBag;
+    ///
+    /// let bag: LinearBag<_> = vec![1, 1, 2].into_iter().collect();
+    /// assert_eq!(bag.total(), 3);
+    /// ```
+    pub fn total(&self) -> usize {
+        self.map.values().sum()
+    }
+
+    /// Removes all elements from the bag.
+    pub fn clear(&mut self) {
+        self.map.clear();
+    }
+
+    /// Adds one occurrence of the given element to the bag and returns its new count.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new count overflows a `usize`.
+    pub fn add(&mut self, value: T) -> usize {
+        self.add_n(value, 1)
+    }
+
+    /// Adds `n` occurrences of the given element to the bag and returns its new count.
+    ///
+    /// Adding zero occurrences of an element that is not in the bag leaves the bag unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new count overflows a `usize`. The bag is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::bag::LinearBag;
+    ///
+    /// let mut bag = LinearBag::new();
+    /// assert_eq!(bag.add_n("x", 2), 2);
+    /// assert_eq!(bag.add("x"), 3);
+    /// assert_eq!(bag.add_n("y", 0), 0);
+    /// assert_eq!(bag.len(), 1);
+    /// ```
+    pub fn add_n(&mut self, value: T, n: usize) -> usize {
+        if n == 0 {
+            return self.count(&value);
+        }
+        let count = self.map.entry(value).or_insert(0);
+        *count = count.checked_add(n).expect("count overflow");
+        *count
+    }
+
+    /// Removes one occurrence of the given element from the bag.
+    ///
+    /// Returns `false` if the bag did not contain the element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::bag::LinearBag;
+    ///
+    /// let mut bag: LinearBag<_> = vec!["x", "x"].into_iter().collect();
+    /// assert!(bag.remove_one("x"));
+    /// assert!(bag.remove_one("x"));
+    /// assert!(!bag.remove_one("x"));
+    /// assert!(bag.is_empty());
+    /// ```
+    pub fn remove_one<Q>(&mut self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: ?Sized + Eq,
+    {
+        self.remove_n(value, 1) == 1
+    }
+
+    /// Removes up to `n` occurrences of the given element from the bag and returns the number of
+    /// occurrences removed.
+    pub fn remove_n<Q>(&mut self, value: &Q, n: usize) -> usize
+    where
+        T: Borrow<Q>,
+        Q: ?Sized + Eq,
+    {
+        let removed = match self.map.get_mut(value) {
+            None => return 0,
+            Some(count) if *count > n => {
+                *count -= n;
+                return n;
+            }
+            Some(count) => *count,
+        };
+        self.map.remove(value);
+        removed
+    }
+
+    /// Removes all occurrences of the given element from the bag and returns their number.
+    pub fn remove_all<Q>(&mut self, value: &Q) -> usize
+    where
+        T: Borrow<Q>,
+        Q: ?Sized + Eq,
+    {
+        self.map.remove(value).unwrap_or(0)
+    }
+
+    /// Returns the number of occurrences of the given element in the bag.
+    pub fn count<Q>(&self, value: &Q) -> usize
+    where
+        T: Borrow<Q>,
+        Q: ?Sized + Eq,
+    {
+        self.map.get(value).cloned().unwrap_or(0)
+    }
+
+    /// Checks if the bag contains at least one occurrence of the given element.
+    pub fn contains<Q>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: ?Sized + Eq,
+    {
+        self.map.contains_key(value)
+    }
+
+    /// Returns the `n` elements with the highest counts, along with their counts, from the
+    /// most common to the least.
+    ///
+    /// Elements with equal counts are ordered as in the bag. All elements are returned if `n`
+    /// is at least the number of distinct elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::bag::LinearBag;
+    ///
+    /// let bag: LinearBag<_> = "hello world".split("").filter(|s| !s.is_empty()).collect();
+    /// assert_eq!(bag.most_common(2), [(&"l", 3), (&"o", 2)]);
+    /// ```
+    pub fn most_common(&self, n: usize) -> Vec<(&T, usize)> {
+        let mut counts: Vec<_> = self.counts().map(|(k, &n)| (k, n)).collect();
+        counts.sort_by_key(|&(_, n)| Reverse(n));
+        counts.truncate(n);
+        counts
+    }
+
+    /// Returns an iterator yielding references to the bag's elements, each repeated as many
+    /// times as it occurs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::bag::LinearBag;
+    ///
+    /// let bag: LinearBag<_> = vec!['a', 'b', 'a'].into_iter().collect();
+    /// let all: Vec<_> = bag.iter().cloned().collect();
+    /// assert_eq!(all, ['a', 'a', 'b']);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            iter: self.map.iter(),
+            current: None,
+        }
+    }
+
+    /// Returns an iterator yielding references to the bag's distinct elements and their counts.
+    ///
+    /// The iterator's item type is `(&T, &usize)`.
+    pub fn counts(&self) -> MapIter<'_, T, usize> {
+        self.map.iter()
+    }
+
+    /// Returns the bag's elements and their counts as a map.
+    pub fn as_map(&self) -> &LinearMap<T, usize> {
+        &self.map
+    }
+
+    /// Converts the bag into a map from its elements to their counts.
+    pub fn into_map(self) -> LinearMap<T, usize> {
+        self.map
+    }
+}
+
+impl<T: Eq> PartialEq for LinearBag<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.map == other.map
+    }
+}
+
+impl<T: Eq> Eq for LinearBag<T> {}
+
+impl<T: Eq + fmt::Debug> fmt::Debug for LinearBag<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.counts()).finish()
+    }
+}
+
+impl<T: Eq> Default for LinearBag<T> {
+    fn default() -> Self {
+        LinearBag::new()
+    }
+}
+
+impl<T: Eq> From<LinearMap<T, usize>> for LinearBag<T> {
+    /// Creates a bag from a map of elements to their counts, dropping the elements with a count
+    /// of zero.
+    fn from(mut map: LinearMap<T, usize>) -> Self {
+        map.retain(|_, n| *n > 0);
+        LinearBag { map }
+    }
+}
+
+impl<T: Eq> From<LinearBag<T>> for LinearMap<T, usize> {
+    fn from(bag: LinearBag<T>) -> Self {
+        bag.map
+    }
+}
+
+impl<T: Eq> FromIterator<T> for LinearBag<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut bag = LinearBag::new();
+        bag.extend(iter);
+        bag
+    }
+}
+
+impl<T: Eq> Extend<T> for LinearBag<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.add(value);
+        }
+    }
+}
+
+impl<'a, T: Eq + Clone> Extend<&'a T> for LinearBag<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().cloned());
+    }
+}
+
+impl<'a, T: Eq> IntoIterator for &'a LinearBag<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<T> Add<&LinearBag<T>> for &LinearBag<T>
+where
+    T: Eq + Clone,
+{
+    type Output = LinearBag<T>;
+
+    /// Returns a bag in which each element occurs as many times as in `self` and `rhs`
+    /// combined.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::bag::LinearBag;
+    ///
+    /// let a: LinearBag<_> = vec![1, 1, 2].into_iter().collect();
+    /// let b: LinearBag<_> = vec![1, 3].into_iter().collect();
+    ///
+    /// let bag = &a + &b;
+    /// assert_eq!((bag.count(&1), bag.count(&2), bag.count(&3)), (3, 1, 1));
+    /// ```
+    fn add(self, rhs: &LinearBag<T>) -> LinearBag<T> {
+        let mut bag = self.clone();
+        for (value, &n) in rhs.counts() {
+            bag.add_n(value.clone(), n);
+        }
+        bag
+    }
+}
+
+impl<T> Sub<&LinearBag<T>> for &LinearBag<T>
+where
+    T: Eq + Clone,
+{
+    type Output = LinearBag<T>;
+
+    /// Returns a bag in which each element occurs as many times as in `self`, less the number
+    /// of times it occurs in `rhs`. Elements that occur at least as often in `rhs` are dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::bag::LinearBag;
+    ///
+    /// let a: LinearBag<_> = vec![1, 1, 2].into_iter().collect();
+    /// let b: LinearBag<_> = vec![1, 2, 3].into_iter().collect();
+    ///
+    /// let bag = &a - &b;
+    /// assert_eq!((bag.count(&1), bag.count(&2), bag.count(&3)), (1, 0, 0));
+    /// ```
+    fn sub(self, rhs: &LinearBag<T>) -> LinearBag<T> {
+        // The elements come from `self`'s map, so they are already unique.
+        let storage = self
+            .counts()
+            .filter_map(|(value, &n)| {
+                let n = n.saturating_sub(rhs.count(value));
+                if n > 0 {
+                    Some((value.clone(), n))
+                } else {
+                    None
+                }
+            })
+            .collect();
+        LinearBag {
+            map: LinearMap::from_vec_unchecked(storage),
+        }
+    }
+}
+
+impl<T> BitAnd<&LinearBag<T>> for &LinearBag<T>
+where
+    T: Eq + Clone,
+{
+    type Output = LinearBag<T>;
+
+    /// Returns a bag in which each element occurs as many times as in whichever of `self` and
+    /// `rhs` it occurs in fewer times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::bag::LinearBag;
+    ///
+    /// let a: LinearBag<_> = vec![1, 1, 2].into_iter().collect();
+    /// let b: LinearBag<_> = vec![1, 3].into_iter().collect();
+    ///
+    /// let bag = &a & &b;
+    /// assert_eq!((bag.count(&1), bag.count(&2), bag.count(&3)), (1, 0, 0));
+    /// ```
+    fn bitand(self, rhs: &LinearBag<T>) -> LinearBag<T> {
+        // The elements come from `self`'s map, so they are already unique.
+        let storage = self
+            .counts()
+            .filter_map(|(value, &n)| {
+                let n = n.min(rhs.count(value));
+                if n > 0 {
+                    Some((value.clone(), n))
+                } else {
+                    None
+                }
+            })
+            .collect();
+        LinearBag {
+            map: LinearMap::from_vec_unchecked(storage),
+        }
+    }
+}
+
+impl<T> BitOr<&LinearBag<T>> for &LinearBag<T>
+where
+    T: Eq + Clone,
+{
+    type Output = LinearBag<T>;
+
+    /// Returns a bag in which each element occurs as many times as in whichever of `self` and
+    /// `rhs` it occurs in more times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::bag::LinearBag;
+    ///
+    /// let a: LinearBag<_> = vec![1, 1, 2].into_iter().collect();
+    /// let b: LinearBag<_> = vec![1, 3].into_iter().collect();
+    ///
+    /// let bag = &a | &b;
+    /// assert_eq!((bag.count(&1), bag.count(&2), bag.count(&3)), (2, 1, 1));
+    /// ```
+    fn bitor(self, rhs: &LinearBag<T>) -> LinearBag<T> {
+        let mut bag = self.clone();
+        for (value, &n) in rhs.counts() {
+            let count = bag.count(value);
+            if n > count {
+                bag.add_n(value.clone(), n - count);
+            }
+        }
+        bag
+    }
+}
+
+/// An iterator yielding references to a `LinearBag`'s elements with multiplicity.
+///
+/// See [`LinearBag::iter`](struct.LinearBag.html#method.iter) for details.
+pub struct Iter<'a, T: 'a> {
+    iter: MapIter<'a, T, usize>,
+    current: Option<(&'a T, usize)>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        loop {
+            if let Some((value, ref mut remaining)) = self.current {
+                if *remaining > 0 {
+                    *remaining -= 1;
+                    return Some(value);
+                }
+            }
+            let (value, &n) = self.iter.next()?;
+            self.current = Some((value, n));
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let current = self.current.map_or(0, |(_, remaining)| remaining);
+        let rest = self.iter.len();
+        (current + rest, if rest == 0 { Some(current) } else { None })
+    }
+}
+
+impl<'a, T> FusedIterator for Iter<'a, T> {}
+
+impl<'a, T> Clone for Iter<'a, T> {
+    fn clone(&self) -> Self {
+        Iter {
+            iter: self.iter.clone(),
+            current: self.current,
+        }
+    }
+}
+
+impl<'a, T: fmt::Debug> fmt::Debug for Iter<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
@@ -0,0 +1,237 @@
+//! Differences between two `LinearMap`s.
+//!
+//! See [`LinearMap::diff`](../struct.LinearMap.html#method.diff) and the [`Patch`](struct.Patch.html)
+//! type for details.
+//!
+//! # Examples
+//!
+//! ```
+//! use linear_map::LinearMap;
+//! use linear_map::diff::{Change, Patch};
+//!
+//! let mut old = LinearMap::new();
+//! old.insert("port", 80);
+//! old.insert("workers", 4);
+//!
+//! let mut new = LinearMap::new();
+//! new.insert("port", 8080);
+//! new.insert("timeout", 30);
+//!
+//! let changes: Vec<_> = old.diff(&new).collect();
+//! assert_eq!(changes, [
+//!     Change::Modified(&"port", &80, &8080),
+//!     Change::Removed(&"workers", &4),
+//!     Change::Added(&"timeout", &30),
+//! ]);
+//!
+//! let patch = Patch::between(&old, &new);
+//! let undo = patch.clone().invert();
+//!
+//! let mut map = old.clone();
+//! patch.apply(&mut map);
+//! assert_eq!(map, new);
+//! undo.apply(&mut map);
+//! assert_eq!(map, old);
+//! ```
+
+use std::iter::FromIterator;
+use std::slice;
+use std::vec;
+
+use super::join::EitherOrBoth::{Both, Left, Right};
+use super::LinearMap;
+
+use self::Change::{Added, Modified, Removed};
+
+/// A single difference between two `LinearMap`s.
+///
+/// See [`LinearMap::diff`](../struct.LinearMap.html#method.diff) for details.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Change<K, V> {
+    /// The key is only present in the new map, with the given value.
+    Added(K, V),
+
+    /// The key is only present in the old map, with the given value.
+    Removed(K, V),
+
+    /// The key is present in both maps, with the given old and new values.
+    Modified(K, V, V),
+}
+
+impl<K, V> Change<K, V> {
+    /// Returns a reference to the key that changed.
+    pub fn key(&self) -> &K {
+        match *self {
+            Added(ref k, _) | Removed(ref k, _) | Modified(ref k, _, _) => k,
+        }
+    }
+
+    /// Returns the change that undoes this one.
+    pub fn invert(self) -> Self {
+        match self {
+            Added(k, v) => Removed(k, v),
+            Removed(k, v) => Added(k, v),
+            Modified(k, old, new) => Modified(k, new, old),
+        }
+    }
+}
+
+impl<'a, K: Clone, V: Clone> Change<&'a K, &'a V> {
+    /// Maps a `Change<&K, &V>` to a `Change<K, V>` by cloning the key and values.
+    pub fn cloned(self) -> Change<K, V> {
+        match self {
+            Added(k, v) => Added(k.clone(), v.clone()),
+            Removed(k, v) => Removed(k.clone(), v.clone()),
+            Modified(k, old, new) => Modified(k.clone(), old.clone(), new.clone()),
+        }
+    }
+}
+
+impl<K: Eq, V: PartialEq> LinearMap<K, V> {
+    /// Returns an iterator yielding the changes that turn this map into `other`.
+    ///
+    /// The keys that were removed or modified are yielded first, in the order of this map,
+    /// followed by the keys that were added, in the order of `other`.
+    ///
+    /// The iterator's item type is `Change<&K, &V>`.
+    pub fn diff<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = Change<&'a K, &'a V>> + 'a {
+        self.outer_join(other)
+            .filter_map(|(k, values)| match values {
+                Left(v) => Some(Removed(k, v)),
+                Right(v) => Some(Added(k, v)),
+                Both(old, new) if old != new => Some(Modified(k, old, new)),
+                Both(_, _) => None,
+            })
+    }
+}
+
+/// An owned list of changes that can be applied to a `LinearMap`.
+///
+/// See the [module documentation](index.html) for an example.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Patch<K, V> {
+    changes: Vec<Change<K, V>>,
+}
+
+impl<K, V> Patch<K, V> {
+    /// Creates an empty patch.
+    pub fn new() -> Self {
+        Patch {
+            changes: Vec::new(),
+        }
+    }
+
+    /// Creates a patch that turns `old` into `new`.
+    pub fn between(old: &LinearMap<K, V>, new: &LinearMap<K, V>) -> Self
+    where
+        K: Eq + Clone,
+        V: PartialEq + Clone,
+    {
+        old.diff(new).map(Change::cloned).collect()
+    }
+
+    /// Returns the number of changes in the patch.
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    /// Checks if the patch contains no changes.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Appends a change to the patch.
+    pub fn push(&mut self, change: Change<K, V>) {
+        self.changes.push(change);
+    }
+
+    /// Returns the changes in the patch as a slice.
+    pub fn changes(&self) -> &[Change<K, V>] {
+        &self.changes
+    }
+
+    /// Returns an iterator yielding references to the changes in the patch.
+    pub fn iter(&self) -> slice::Iter<'_, Change<K, V>> {
+        self.changes.iter()
+    }
+
+    /// Returns the patch that undoes this one.
+    ///
+    /// The changes are inverted and reversed, so applying a patch and then its inverse leaves a
+    /// map that the patch applied cleanly to with the same key-value pairs.
+    pub fn invert(self) -> Self {
+        Patch {
+            changes: self.changes.into_iter().rev().map(Change::invert).collect(),
+        }
+    }
+
+    /// Applies the changes in the patch to the given map, in order.
+    ///
+    /// Added and modified keys are inserted with their new values, and removed keys are removed.
+    /// The old values recorded in the patch are not checked against the map.
+    pub fn apply(self, map: &mut LinearMap<K, V>)
+    where
+        K: Eq,
+    {
+        for change in self.changes {
+            match change {
+                Added(k, v) | Modified(k, _, v) => {
+                    map.insert(k, v);
+                }
+                Removed(k, _) => {
+                    map.remove(&k);
+                }
+            }
+        }
+    }
+}
+
+impl<K, V> Default for Patch<K, V> {
+    fn default() -> Self {
+        Patch::new()
+    }
+}
+
+impl<K, V> From<Vec<Change<K, V>>> for Patch<K, V> {
+    fn from(changes: Vec<Change<K, V>>) -> Self {
+        Patch { changes }
+    }
+}
+
+impl<K, V> From<Patch<K, V>> for Vec<Change<K, V>> {
+    fn from(patch: Patch<K, V>) -> Self {
+        patch.changes
+    }
+}
+
+impl<K, V> FromIterator<Change<K, V>> for Patch<K, V> {
+    fn from_iter<I: IntoIterator<Item = Change<K, V>>>(iter: I) -> Self {
+        Patch {
+            changes: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<K, V> Extend<Change<K, V>> for Patch<K, V> {
+    fn extend<I: IntoIterator<Item = Change<K, V>>>(&mut self, iter: I) {
+        self.changes.extend(iter);
+    }
+}
+
+impl<K, V> IntoIterator for Patch<K, V> {
+    type Item = Change<K, V>;
+    type IntoIter = vec::IntoIter<Change<K, V>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.changes.into_iter()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a Patch<K, V> {
+    type Item = &'a Change<K, V>;
+    type IntoIter = slice::Iter<'a, Change<K, V>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -0,0 +1,127 @@
+//! Grouping and counting the items of an iterator into a `LinearMap`.
+//!
+//! See [`LinearMap::group_by`](../struct.LinearMap.html#method.group_by),
+//! [`LinearMap::counts`](../struct.LinearMap.html#method.counts) and
+//! [`LinearMap::fold_into`](../struct.LinearMap.html#method.fold_into) for details.
+//!
+//! # Examples
+//!
+//! ```
+//! use linear_map::LinearMap;
+//! use linear_map::group::{Counts, GroupedBy};
+//!
+//! let words = ["apple", "bean", "avocado", "beet", "cherry"];
+//!
+//! let by_letter = LinearMap::group_by(words.iter().cloned(), |w| w.as_bytes()[0]);
+//! assert_eq!(by_letter[&b'a'], ["apple", "avocado"]);
+//!
+//! let lengths = LinearMap::counts(words.iter().map(|w| w.len()));
+//! assert_eq!(lengths[&4], 2);
+//!
+//! let GroupedBy(by_length) = words.iter().map(|w| (w.len(), *w)).collect();
+//! assert_eq!(by_length[&6], ["cherry"]);
+//!
+//! let Counts(letters) = words.iter().flat_map(|w| w.chars()).collect();
+//! assert_eq!(letters[&'e'], 5);
+//! ```
+
+use std::iter::FromIterator;
+
+use super::LinearMap;
+
+impl<K: Eq, V> LinearMap<K, V> {
+    /// Creates a map by folding the items of the given iterator into the value of the key that
+    /// `key_fn` returns for them.
+    ///
+    /// The value of each key starts out as the result of `init`. The keys are ordered by their
+    /// first item.
+    pub fn fold_into<T, I, KF, IF, F>(iter: I, mut key_fn: KF, init: IF, fold: F) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        KF: FnMut(&T) -> K,
+        IF: FnMut() -> V,
+        F: FnMut(&mut V, T),
+    {
+        let pairs = iter.into_iter().map(|item| (key_fn(&item), item));
+        LinearMap::fold_pairs(pairs, init, fold)
+    }
+
+    /// Like `fold_into`, but for an iterator of items already paired with their keys.
+    fn fold_pairs<T, I, IF, F>(pairs: I, mut init: IF, mut fold: F) -> Self
+    where
+        I: IntoIterator<Item = (K, T)>,
+        IF: FnMut() -> V,
+        F: FnMut(&mut V, T),
+    {
+        let mut map = LinearMap::new();
+        for (key, item) in pairs {
+            fold(map.entry(key).or_insert_with(&mut init), item);
+        }
+        map
+    }
+}
+
+impl<K: Eq, V> LinearMap<K, Vec<V>> {
+    /// Creates a map from the keys that `key_fn` returns for the items of the given iterator to
+    /// the items, in iteration order.
+    ///
+    /// The keys are ordered by their first item.
+    pub fn group_by<I, F>(iter: I, key_fn: F) -> Self
+    where
+        I: IntoIterator<Item = V>,
+        F: FnMut(&V) -> K,
+    {
+        LinearMap::fold_into(iter, key_fn, Vec::new, Vec::push)
+    }
+}
+
+impl<T: Eq> LinearMap<T, usize> {
+    /// Creates a map from the distinct items of the given iterator to the number of times they
+    /// occur.
+    ///
+    /// The items are ordered by their first occurrence.
+    pub fn counts<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let pairs = iter.into_iter().map(|item| (item, ()));
+        LinearMap::fold_pairs(pairs, || 0, |count, ()| *count += 1)
+    }
+}
+
+/// A map from keys to the values paired with them, collected from an iterator of key-value
+/// pairs.
+///
+/// See the [module documentation](index.html) for an example.
+#[derive(Clone)]
+pub struct GroupedBy<K, V>(pub LinearMap<K, Vec<V>>);
+
+impl<K: Eq, V> FromIterator<(K, V)> for GroupedBy<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        GroupedBy(LinearMap::fold_pairs(iter, Vec::new, Vec::push))
+    }
+}
+
+impl<K, V> From<GroupedBy<K, V>> for LinearMap<K, Vec<V>> {
+    fn from(grouped: GroupedBy<K, V>) -> Self {
+        grouped.0
+    }
+}
+
+/// A map from the distinct items of an iterator to the number of times they occur.
+///
+/// See the [module documentation](index.html) for an example.
+#[derive(Clone)]
+pub struct Counts<T>(pub LinearMap<T, usize>);
+
+impl<T: Eq> FromIterator<T> for Counts<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Counts(LinearMap::counts(iter))
+    }
+}
+
+impl<T> From<Counts<T>> for LinearMap<T, usize> {
+    fn from(counts: Counts<T>) -> Self {
+        counts.0
+    }
+}
@@ -0,0 +1,44 @@
+//! Optional conversions between the linear collections and those of the `hashbrown` crate.
+
+extern crate hashbrown;
+
+use super::set::LinearSet;
+use super::LinearMap;
+
+use self::hashbrown::{HashMap, HashSet};
+
+use std::hash::{BuildHasher, Hash};
+
+impl<K: Eq, V, S> From<HashMap<K, V, S>> for LinearMap<K, V> {
+    /// Creates a map from a `hashbrown::HashMap`, in the `HashMap`'s iteration order.
+    fn from(other: HashMap<K, V, S>) -> Self {
+        LinearMap::from_unique(other)
+    }
+}
+
+impl<K, V, S> From<LinearMap<K, V>> for HashMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    fn from(other: LinearMap<K, V>) -> Self {
+        other.into_iter().collect()
+    }
+}
+
+impl<T: Eq, S> From<HashSet<T, S>> for LinearSet<T> {
+    /// Creates a LinearSet from a `hashbrown::HashSet`, in the `HashSet`'s iteration order.
+    fn from(other: HashSet<T, S>) -> Self {
+        LinearSet::from_unique(other)
+    }
+}
+
+impl<T, S> From<LinearSet<T>> for HashSet<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    fn from(other: LinearSet<T>) -> Self {
+        other.into_iter().collect()
+    }
+}
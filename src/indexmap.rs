@@ -0,0 +1,46 @@
+//! Optional conversions between the linear collections and those of the `indexmap` crate.
+
+extern crate indexmap;
+
+use super::set::LinearSet;
+use super::LinearMap;
+
+use self::indexmap::{IndexMap, IndexSet};
+
+use std::hash::{BuildHasher, Hash};
+
+impl<K: Eq, V, S> From<IndexMap<K, V, S>> for LinearMap<K, V> {
+    /// Creates a map from an `IndexMap`, keeping its order.
+    fn from(other: IndexMap<K, V, S>) -> Self {
+        LinearMap::from_unique(other)
+    }
+}
+
+impl<K, V, S> From<LinearMap<K, V>> for IndexMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    /// Creates an `IndexMap` from a map, keeping its order.
+    fn from(other: LinearMap<K, V>) -> Self {
+        other.into_iter().collect()
+    }
+}
+
+impl<T: Eq, S> From<IndexSet<T, S>> for LinearSet<T> {
+    /// Creates a LinearSet from an `IndexSet`, keeping its order.
+    fn from(other: IndexSet<T, S>) -> Self {
+        LinearSet::from_unique(other)
+    }
+}
+
+impl<T, S> From<LinearSet<T>> for IndexSet<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    /// Creates an `IndexSet` from a LinearSet, keeping its order.
+    fn from(other: LinearSet<T>) -> Self {
+        other.into_iter().collect()
+    }
+}
@@ -0,0 +1,369 @@
+//! Joins between `LinearMap`s that share a key type.
+//!
+//! See [`LinearMap::inner_join`](../struct.LinearMap.html#method.inner_join),
+//! [`LinearMap::left_join`](../struct.LinearMap.html#method.left_join) and
+//! [`LinearMap::outer_join`](../struct.LinearMap.html#method.outer_join) for details.
+//!
+//! # Examples
+//!
+//! ```
+//! use linear_map::LinearMap;
+//! use linear_map::join::EitherOrBoth::{Both, Left, Right};
+//!
+//! let mut sales = LinearMap::new();
+//! sales.insert("north", 10);
+//! sales.insert("south", 20);
+//!
+//! let mut returns = LinearMap::new();
+//! returns.insert("south", 2);
+//! returns.insert("west", 1);
+//!
+//! let inner: Vec<_> = sales.inner_join(&returns).collect();
+//! assert_eq!(inner, [(&"south", &20, &2)]);
+//!
+//! let left: Vec<_> = sales.left_join(&returns).collect();
+//! assert_eq!(left, [(&"north", &10, None), (&"south", &20, Some(&2))]);
+//!
+//! let outer: Vec<_> = sales.outer_join(&returns).collect();
+//! assert_eq!(outer, [(&"north", Left(&10)), (&"south", Both(&20, &2)), (&"west", Right(&1))]);
+//! ```
+
+use std::fmt;
+use std::iter::FusedIterator;
+
+use super::{Iter, LinearMap};
+
+use self::EitherOrBoth::{Both, Left, Right};
+
+/// A value that is present in the left map, the right map, or both.
+///
+/// See [`LinearMap::outer_join`](../struct.LinearMap.html#method.outer_join) for details.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EitherOrBoth<L, R> {
+    /// The key is only present in the left map.
+    Left(L),
+
+    /// The key is only present in the right map.
+    Right(R),
+
+    /// The key is present in both maps.
+    Both(L, R),
+}
+
+impl<L, R> EitherOrBoth<L, R> {
+    /// Returns the left value, if any.
+    pub fn left(self) -> Option<L> {
+        match self {
+            Left(l) | Both(l, _) => Some(l),
+            Right(_) => None,
+        }
+    }
+
+    /// Returns the right value, if any.
+    pub fn right(self) -> Option<R> {
+        match self {
+            Right(r) | Both(_, r) => Some(r),
+            Left(_) => None,
+        }
+    }
+
+    /// Returns both values if both are present.
+    pub fn both(self) -> Option<(L, R)> {
+        match self {
+            Both(l, r) => Some((l, r)),
+            _ => None,
+        }
+    }
+
+    /// Converts from `&EitherOrBoth<L, R>` to `EitherOrBoth<&L, &R>`.
+    pub fn as_ref(&self) -> EitherOrBoth<&L, &R> {
+        match *self {
+            Left(ref l) => Left(l),
+            Right(ref r) => Right(r),
+            Both(ref l, ref r) => Both(l, r),
+        }
+    }
+}
+
+impl<K: Eq, V> LinearMap<K, V> {
+    /// Returns an iterator yielding the keys that are present in both maps along with their
+    /// corresponding values, in the order of this map.
+    ///
+    /// The iterator's item type is `(&K, &V, &V2)`.
+    pub fn inner_join<'a, V2>(&'a self, other: &'a LinearMap<K, V2>) -> InnerJoin<'a, K, V, V2> {
+        InnerJoin {
+            iter: self.iter(),
+            other,
+        }
+    }
+
+    /// Returns an iterator yielding the keys of this map along with their corresponding values
+    /// in both maps, in the order of this map.
+    ///
+    /// The iterator's item type is `(&K, &V, Option<&V2>)`.
+    pub fn left_join<'a, V2>(&'a self, other: &'a LinearMap<K, V2>) -> LeftJoin<'a, K, V, V2> {
+        LeftJoin {
+            iter: self.iter(),
+            other,
+        }
+    }
+
+    /// Returns an iterator yielding the keys of either map along with their corresponding
+    /// values.
+    ///
+    /// The keys of this map are yielded first, in its order, followed by the keys that are only
+    /// present in `other`, in the order of `other`.
+    ///
+    /// The iterator's item type is `(&K, EitherOrBoth<&V, &V2>)`.
+    pub fn outer_join<'a, V2>(&'a self, other: &'a LinearMap<K, V2>) -> OuterJoin<'a, K, V, V2> {
+        OuterJoin {
+            left: self,
+            right: other,
+            left_iter: self.iter(),
+            right_iter: other.iter(),
+        }
+    }
+
+    /// Consumes both maps and returns a map from the keys that are present in both to their
+    /// corresponding pairs of values, in the order of this map.
+    pub fn into_inner_join<V2>(self, other: LinearMap<K, V2>) -> LinearMap<K, (V, V2)> {
+        let mut storage = Vec::new();
+        let mut other = Remaining::new(other);
+        for (k, v) in self.storage {
+            if let Some(v2) = other.take(&k) {
+                storage.push((k, (v, v2)));
+            }
+        }
+        LinearMap { storage }
+    }
+
+    /// Consumes both maps and returns a map from the keys of this map to their corresponding
+    /// values in both maps, in the order of this map.
+    pub fn into_left_join<V2>(self, other: LinearMap<K, V2>) -> LinearMap<K, (V, Option<V2>)> {
+        let mut other = Remaining::new(other);
+        let storage = self
+            .storage
+            .into_iter()
+            .map(|(k, v)| {
+                let v2 = other.take(&k);
+                (k, (v, v2))
+            })
+            .collect();
+        LinearMap { storage }
+    }
+
+    /// Consumes both maps and returns a map from the keys of either map to their corresponding
+    /// values.
+    ///
+    /// The keys of this map come first, in its order, followed by the keys that are only present
+    /// in `other`, in the order of `other`.
+    pub fn into_outer_join<V2>(self, other: LinearMap<K, V2>) -> LinearMap<K, EitherOrBoth<V, V2>> {
+        let mut other = Remaining::new(other);
+        let mut storage: Vec<_> = self
+            .storage
+            .into_iter()
+            .map(|(k, v)| match other.take(&k) {
+                Some(v2) => (k, Both(v, v2)),
+                None => (k, Left(v)),
+            })
+            .collect();
+        storage.extend(other.into_iter().map(|(k, v2)| (k, Right(v2))));
+        LinearMap { storage }
+    }
+}
+
+/// The key-value pairs of a consumed map that have not been joined yet, kept in their original
+/// order.
+struct Remaining<K, V> {
+    storage: Vec<Option<(K, V)>>,
+}
+
+impl<K: Eq, V> Remaining<K, V> {
+    fn new(map: LinearMap<K, V>) -> Self {
+        Remaining {
+            storage: map.storage.into_iter().map(Some).collect(),
+        }
+    }
+
+    fn take(&mut self, key: &K) -> Option<V> {
+        self.storage
+            .iter_mut()
+            .find(|e| matches!(e, Some((k, _)) if *k == *key))
+            .and_then(Option::take)
+            .map(|(_, v)| v)
+    }
+
+    fn into_iter(self) -> impl Iterator<Item = (K, V)> {
+        self.storage.into_iter().flatten()
+    }
+}
+
+/// An iterator over the keys that are present in both of two `LinearMap`s.
+///
+/// See [`LinearMap::inner_join`](../struct.LinearMap.html#method.inner_join) for details.
+pub struct InnerJoin<'a, K: 'a, V1: 'a, V2: 'a> {
+    iter: Iter<'a, K, V1>,
+    other: &'a LinearMap<K, V2>,
+}
+
+/// An iterator over the keys of a `LinearMap` and their values in it and another map.
+///
+/// See [`LinearMap::left_join`](../struct.LinearMap.html#method.left_join) for details.
+pub struct LeftJoin<'a, K: 'a, V1: 'a, V2: 'a> {
+    iter: Iter<'a, K, V1>,
+    other: &'a LinearMap<K, V2>,
+}
+
+/// An iterator over the keys of either of two `LinearMap`s.
+///
+/// See [`LinearMap::outer_join`](../struct.LinearMap.html#method.outer_join) for details.
+pub struct OuterJoin<'a, K: 'a, V1: 'a, V2: 'a> {
+    left: &'a LinearMap<K, V1>,
+    right: &'a LinearMap<K, V2>,
+    left_iter: Iter<'a, K, V1>,
+    right_iter: Iter<'a, K, V2>,
+}
+
+impl<'a, K, V1, V2> Clone for InnerJoin<'a, K, V1, V2> {
+    fn clone(&self) -> Self {
+        InnerJoin {
+            iter: self.iter.clone(),
+            other: self.other,
+        }
+    }
+}
+
+impl<'a, K: Eq, V1, V2> Iterator for InnerJoin<'a, K, V1, V2> {
+    type Item = (&'a K, &'a V1, &'a V2);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let other = self.other;
+        self.iter
+            .by_ref()
+            .filter_map(|(k, v1)| other.get(k).map(|v2| (k, v1, v2)))
+            .next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<'a, K: Eq, V1, V2> DoubleEndedIterator for InnerJoin<'a, K, V1, V2> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let other = self.other;
+        self.iter
+            .by_ref()
+            .rev()
+            .filter_map(|(k, v1)| other.get(k).map(|v2| (k, v1, v2)))
+            .next()
+    }
+}
+
+impl<'a, K: Eq, V1, V2> FusedIterator for InnerJoin<'a, K, V1, V2> {}
+
+impl<'a, K, V1, V2> fmt::Debug for InnerJoin<'a, K, V1, V2>
+where
+    K: Eq + fmt::Debug,
+    V1: fmt::Debug,
+    V2: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<'a, K, V1, V2> Clone for LeftJoin<'a, K, V1, V2> {
+    fn clone(&self) -> Self {
+        LeftJoin {
+            iter: self.iter.clone(),
+            other: self.other,
+        }
+    }
+}
+
+impl<'a, K: Eq, V1, V2> Iterator for LeftJoin<'a, K, V1, V2> {
+    type Item = (&'a K, &'a V1, Option<&'a V2>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let other = self.other;
+        self.iter.next().map(|(k, v1)| (k, v1, other.get(k)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, K: Eq, V1, V2> DoubleEndedIterator for LeftJoin<'a, K, V1, V2> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let other = self.other;
+        self.iter.next_back().map(|(k, v1)| (k, v1, other.get(k)))
+    }
+}
+
+impl<'a, K: Eq, V1, V2> ExactSizeIterator for LeftJoin<'a, K, V1, V2> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<'a, K: Eq, V1, V2> FusedIterator for LeftJoin<'a, K, V1, V2> {}
+
+impl<'a, K, V1, V2> fmt::Debug for LeftJoin<'a, K, V1, V2>
+where
+    K: Eq + fmt::Debug,
+    V1: fmt::Debug,
+    V2: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<'a, K, V1, V2> Clone for OuterJoin<'a, K, V1, V2> {
+    fn clone(&self) -> Self {
+        OuterJoin {
+            left: self.left,
+            right: self.right,
+            left_iter: self.left_iter.clone(),
+            right_iter: self.right_iter.clone(),
+        }
+    }
+}
+
+impl<'a, K: Eq, V1, V2> Iterator for OuterJoin<'a, K, V1, V2> {
+    type Item = (&'a K, EitherOrBoth<&'a V1, &'a V2>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some((k, v1)) = self.left_iter.next() {
+            return match self.right.get(k) {
+                Some(v2) => Some((k, Both(v1, v2))),
+                None => Some((k, Left(v1))),
+            };
+        }
+        let left = self.left;
+        self.right_iter
+            .by_ref()
+            .find(|&(k, _)| !left.contains_key(k))
+            .map(|(k, v2)| (k, Right(v2)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.left_iter.len();
+        (len, Some(len + self.right_iter.len()))
+    }
+}
+
+impl<'a, K: Eq, V1, V2> FusedIterator for OuterJoin<'a, K, V1, V2> {}
+
+impl<'a, K, V1, V2> fmt::Debug for OuterJoin<'a, K, V1, V2>
+where
+    K: Eq + fmt::Debug,
+    V1: fmt::Debug,
+    V2: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
@@ -4,19 +4,37 @@
 
 #![deny(missing_docs)]
 
+pub mod bag;
+pub mod diff;
+pub mod group;
+pub mod join;
+pub mod set;
+
 // Optional Serde support
 #[cfg(feature = "serde_impl")]
 pub mod serde;
-pub mod set;
+
+// Optional conversions to and from other crates' collections
+#[cfg(feature = "hashbrown")]
+mod hashbrown;
+#[cfg(feature = "indexmap")]
+mod indexmap;
 
 use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::collections::hash_map::{self, DefaultHasher};
+use std::collections::{btree_map, BTreeMap, BTreeSet, HashMap, HashSet};
+use std::error::Error;
 use std::fmt::{self, Debug};
-use std::iter;
+use std::hash::{BuildHasher, Hash, Hasher};
+use std::iter::{self, FusedIterator};
 use std::mem;
 use std::ops;
+use std::ptr;
 use std::slice;
 use std::vec;
 
+use self::set::{LinearSet, SetLike};
 use self::Entry::{Occupied, Vacant};
 
 /// A map implemented by searching linearly in a vector.
@@ -24,7 +42,8 @@ use self::Entry::{Occupied, Vacant};
 /// `LinearMap`'s keys are compared using the [`Eq`][eq] trait. All search operations
 /// (`contains_key`, `get`, `get_mut`, `insert`, and `remove`) run in `O(n)` time, making this
 /// implementation suitable only for small numbers of keys. The ordering of the keys in the
-/// underlying vector is arbitrary.
+/// underlying vector is arbitrary, but it can be set explicitly with
+/// [`sort_keys`](#method.sort_keys) and the other sorting methods.
 ///
 /// It is a logic error for a key to be modified in such a way that the key's equality, as
 /// determined by the [`Eq`][eq] trait, changes while it is in the map. This is normally only
@@ -89,6 +108,41 @@ impl<K: Eq, V> LinearMap<K, V> {
         }
     }
 
+    /// Creates a map from a vector of key-value pairs, checking that the keys are unique.
+    ///
+    /// Returns an error naming the positions of the first two pairs with equal keys otherwise.
+    pub fn try_from_vec(storage: Vec<(K, V)>) -> Result<Self, DuplicateKeyError> {
+        match first_duplicate(&storage) {
+            Some((first, second)) => Err(DuplicateKeyError { first, second }),
+            None => Ok(LinearMap { storage }),
+        }
+    }
+
+    /// Creates a map from a vector of key-value pairs, dropping pairs with duplicate keys
+    /// according to the given policy.
+    ///
+    /// The remaining pairs keep their relative order. A pair that wins over an earlier one takes
+    /// its position. The vector's allocation is reused.
+    pub fn from_vec_dedup(mut storage: Vec<(K, V)>, policy: DedupPolicy) -> Self {
+        dedup(&mut storage, policy);
+        LinearMap { storage }
+    }
+
+    /// Creates a map from a vector of key-value pairs without checking that the keys are unique.
+    ///
+    /// It is a logic error for the vector to contain two pairs with equal keys. The behavior
+    /// resulting from such a logic error is not specified, but will not result in undefined
+    /// behavior.
+    pub fn from_vec_unchecked(storage: Vec<(K, V)>) -> Self {
+        LinearMap { storage }
+    }
+
+    /// Creates a map from the pairs of another map, whose keys are already unique and so are not
+    /// compared. Used by the conversions from other crates' maps.
+    pub(crate) fn from_unique<I: IntoIterator<Item = (K, V)>>(pairs: I) -> Self {
+        LinearMap::from_vec_unchecked(pairs.into_iter().collect())
+    }
+
     /// Returns the number of elements the map can hold without reallocating.
     pub fn capacity(&self) -> usize {
         self.storage.capacity()
@@ -169,16 +223,271 @@ impl<K: Eq, V> LinearMap<K, V> {
         }
     }
 
-    /// Removes all key-value pairs from the map and returns an iterator that yields them in
-    /// arbitrary order.
+    /// Creates an iterator that removes the key-value pairs for which the closure returns `true`
+    /// and yields them.
+    ///
+    /// Pairs for which the closure returns `false` remain in the map, and so do any pairs the
+    /// iterator has not visited yet when it is dropped.
+    ///
+    /// The order the elements are visited is not specified.
+    ///
+    /// The iterator's item type is `(K, V)`.
+    pub fn extract_if<F>(&mut self, pred: F) -> ExtractIf<'_, K, V, F>
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        ExtractIf {
+            storage: &mut self.storage,
+            index: 0,
+            pred,
+        }
+    }
+
+    /// Splits the map in two, according to the given closure.
+    ///
+    /// The first map contains the key-value pairs for which the closure returns `true`, the
+    /// second map those for which it returns `false`. The second map reuses the allocation of
+    /// `self`.
+    pub fn partition<F>(mut self, mut pred: F) -> (Self, Self)
+    where
+        F: FnMut(&K, &V) -> bool,
+    {
+        let matched = self.extract_if(|k, v| pred(k, v)).collect();
+        (LinearMap { storage: matched }, self)
+    }
+
+    /// Converts the map into one with the same keys, in the same order, whose values are the
+    /// results of the given function.
+    ///
+    /// This runs in `O(n)` time, as the keys are known to be unique.
+    pub fn map_values<W, F>(self, mut f: F) -> LinearMap<K, W>
+    where
+        F: FnMut(&K, V) -> W,
+    {
+        let storage = self
+            .storage
+            .into_iter()
+            .map(|(key, value)| {
+                let value = f(&key, value);
+                (key, value)
+            })
+            .collect();
+        LinearMap { storage }
+    }
+
+    /// Converts the map into one with the keys, in the same order, for which the given function
+    /// returns `Some`, and the values it returns.
+    ///
+    /// This runs in `O(n)` time, as the keys are known to be unique.
+    pub fn filter_map_values<W, F>(self, mut f: F) -> LinearMap<K, W>
+    where
+        F: FnMut(&K, V) -> Option<W>,
+    {
+        let storage = self
+            .storage
+            .into_iter()
+            .filter_map(|(key, value)| f(&key, value).map(|value| (key, value)))
+            .collect();
+        LinearMap { storage }
+    }
+
+    /// Converts the map into one whose keys are the results of the given function.
+    ///
+    /// When the function maps several keys to equal keys, only one of their pairs is kept,
+    /// according to `policy`; the pairs are ordered as in `self`. This runs in `O(n²)` time.
+    pub fn map_keys<J, F>(self, mut f: F, policy: DedupPolicy) -> LinearMap<J, V>
+    where
+        J: Eq,
+        F: FnMut(K) -> J,
+    {
+        let mut storage: Vec<_> = self
+            .storage
+            .into_iter()
+            .map(|(key, value)| (f(key), value))
+            .collect();
+        dedup(&mut storage, policy);
+        LinearMap { storage }
+    }
+
+    /// Converts the map into one from its values to their keys.
+    ///
+    /// When several keys have equal values, only one of their pairs is kept, according to
+    /// `policy`; the pairs are ordered as in `self`. This runs in `O(n²)` time.
+    pub fn invert(self, policy: DedupPolicy) -> LinearMap<V, K>
+    where
+        V: Eq,
+    {
+        let mut storage: Vec<_> = self.storage.into_iter().map(|(k, v)| (v, k)).collect();
+        dedup(&mut storage, policy);
+        LinearMap { storage }
+    }
+
+    /// Sorts the map's key-value pairs by key.
+    pub fn sort_keys(&mut self)
+    where
+        K: Ord,
+    {
+        self.storage.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+    }
+
+    /// Sorts the map's key-value pairs with the given comparison function.
+    ///
+    /// The comparison function receives the key and value of two pairs. The sort is stable.
+    pub fn sort_by<F>(&mut self, mut cmp: F)
+    where
+        F: FnMut(&K, &V, &K, &V) -> Ordering,
+    {
+        self.storage.sort_by(|a, b| cmp(&a.0, &a.1, &b.0, &b.1));
+    }
+
+    /// Sorts the map's key-value pairs with the given key extraction function.
+    ///
+    /// The sort is unstable, i.e. pairs for which the function returns equal sort keys may be
+    /// reordered.
+    pub fn sort_unstable_by_key<T, F>(&mut self, mut f: F)
+    where
+        T: Ord,
+        F: FnMut(&K, &V) -> T,
+    {
+        self.storage.sort_unstable_by_key(|e| f(&e.0, &e.1));
+    }
+
+    /// Sorts the map's key-value pairs with the given key extraction function, calling it only
+    /// once per pair.
+    ///
+    /// This is faster than `sort_unstable_by_key` for expensive key extraction functions. The
+    /// sort is stable.
+    pub fn sort_by_cached_key<T, F>(&mut self, mut f: F)
+    where
+        T: Ord,
+        F: FnMut(&K, &V) -> T,
+    {
+        self.storage.sort_by_cached_key(|e| f(&e.0, &e.1));
+    }
+
+    /// Reverses the order of the map's key-value pairs.
+    pub fn reverse(&mut self) {
+        self.storage.reverse();
+    }
+
+    /// Returns an iterator yielding references to the map's keys and their corresponding values
+    /// in key order, without reordering the map itself.
+    ///
+    /// The iterator's item type is `(&K, &V)`.
+    pub fn sorted_iter(&self) -> SortedIter<'_, K, V>
+    where
+        K: Ord,
+    {
+        let mut entries: Vec<_> = self.iter().collect();
+        entries.sort_unstable_by(|a, b| a.0.cmp(b.0));
+        SortedIter {
+            iter: entries.into_iter(),
+        }
+    }
+
+    /// Removes the key-value pairs in the given range of positions from the map and returns an
+    /// iterator that yields them. Use `..` to remove all of them.
     ///
-    /// All key-value pairs are removed even if the iterator is not exhausted. However, the
-    /// behavior of this method is unspecified if the iterator is leaked.
+    /// The pairs following the range keep their order. All pairs in the range are removed even
+    /// if the iterator is not exhausted. However, the behavior of this method is unspecified if
+    /// the iterator is leaked.
     ///
     /// The iterator's item type is `(K, V)`.
-    pub fn drain(&mut self) -> Drain<K, V> {
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is greater than its end or if its end is greater than
+    /// the length of the map.
+    pub fn drain<R>(&mut self, range: R) -> Drain<'_, K, V>
+    where
+        R: ops::RangeBounds<usize>,
+    {
         Drain {
-            iter: self.storage.drain(..),
+            iter: self.storage.drain(range),
+        }
+    }
+
+    /// Splits the map in two at the given position.
+    ///
+    /// Returns a newly allocated map containing the key-value pairs at positions `at..`, while
+    /// `self` keeps those at positions `..at` and its capacity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > len`.
+    pub fn split_off(&mut self, at: usize) -> Self {
+        LinearMap {
+            storage: self.storage.split_off(at),
+        }
+    }
+
+    /// Shortens the map, keeping the first `len` key-value pairs and dropping the rest.
+    ///
+    /// Does nothing if `len` is greater than or equal to the map's current length.
+    pub fn truncate(&mut self, len: usize) {
+        self.storage.truncate(len);
+    }
+
+    /// Removes the last key-value pair from the map and returns it.
+    ///
+    /// Returns `None` if the map is empty.
+    pub fn pop(&mut self) -> Option<(K, V)> {
+        self.storage.pop()
+    }
+
+    /// Moves all key-value pairs from `other` into `self`, leaving `other` empty but keeping its
+    /// capacity.
+    ///
+    /// If a key from `other` is already present in `self`, the value from `other` replaces the
+    /// value in `self`, as with [`insert`](#method.insert).
+    pub fn append(&mut self, other: &mut Self) {
+        self.storage.reserve(other.len());
+        for (key, value) in other.storage.drain(..) {
+            self.insert(key, value);
+        }
+    }
+
+    /// Moves all key-value pairs from `other` into `self`, combining the values of keys that are
+    /// present in both maps with the given function.
+    ///
+    /// The function is called with the key, the value in `self` and the value in `other`, and
+    /// its result becomes the key's value in `self`. Keys that are only present in `other` are
+    /// appended in the order of `other`.
+    ///
+    /// If the function panics, the key it was called with is removed from `self`, since its
+    /// value has been lost, and the pairs of `other` that were not moved yet are dropped.
+    pub fn merge_with<F>(&mut self, other: Self, mut f: F)
+    where
+        F: FnMut(&K, V, V) -> V,
+    {
+        self.storage.reserve(other.len());
+        for (key, incoming) in other.storage {
+            match self.storage.iter().position(|e| e.0 == key) {
+                Some(index) => {
+                    self.replace_value_with(index, |k, v| Some(f(k, v, incoming)));
+                }
+                None => self.storage.push((key, incoming)),
+            }
+        }
+    }
+
+    /// Clones all key-value pairs from `other` into `self`, combining the values of keys that are
+    /// present in both maps with the given function.
+    ///
+    /// See [`merge_with`](#method.merge_with) for details.
+    pub fn merge_from<F>(&mut self, other: &Self, mut f: F)
+    where
+        K: Clone,
+        V: Clone,
+        F: FnMut(&K, V, V) -> V,
+    {
+        for (key, incoming) in other {
+            match self.storage.iter().position(|e| e.0 == *key) {
+                Some(index) => {
+                    self.replace_value_with(index, |k, v| Some(f(k, v, incoming.clone())));
+                }
+                None => self.storage.push((key.clone(), incoming.clone())),
+            }
         }
     }
 
@@ -186,7 +495,7 @@ impl<K: Eq, V> LinearMap<K, V> {
     /// arbitrary order.
     ///
     /// The iterator's item type is `(&K, &V)`.
-    pub fn iter(&self) -> Iter<K, V> {
+    pub fn iter(&self) -> Iter<'_, K, V> {
         Iter {
             iter: self.storage.iter(),
         }
@@ -196,7 +505,7 @@ impl<K: Eq, V> LinearMap<K, V> {
     /// corresponding values in arbitrary order.
     ///
     /// The iterator's item type is `(&K, &mut V)`.
-    pub fn iter_mut(&mut self) -> IterMut<K, V> {
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
         IterMut {
             iter: self.storage.iter_mut(),
         }
@@ -205,17 +514,83 @@ impl<K: Eq, V> LinearMap<K, V> {
     /// Returns an iterator yielding references to the map's keys in arbitrary order.
     ///
     /// The iterator's item type is `&K`.
-    pub fn keys(&self) -> Keys<K, V> {
+    pub fn keys(&self) -> Keys<'_, K, V> {
         Keys { iter: self.iter() }
     }
 
     /// Returns an iterator yielding references to the map's values in arbitrary order.
     ///
     /// The iterator's item type is `&V`.
-    pub fn values(&self) -> Values<K, V> {
+    pub fn values(&self) -> Values<'_, K, V> {
         Values { iter: self.iter() }
     }
 
+    /// Returns a view of the map's keys as a set, which can be compared with and combined with
+    /// other sets without collecting the keys.
+    pub fn key_set(&self) -> KeySet<'_, K, V> {
+        KeySet { map: self }
+    }
+
+    /// Returns an iterator yielding mutable references to the map's values in arbitrary order.
+    ///
+    /// The iterator's item type is `&mut V`.
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+        ValuesMut {
+            iter: self.iter_mut(),
+        }
+    }
+
+    /// Creates a consuming iterator yielding the map's keys in arbitrary order.
+    ///
+    /// The map cannot be used after calling this. The iterator's item type is `K`.
+    pub fn into_keys(self) -> IntoKeys<K, V> {
+        IntoKeys {
+            iter: self.storage.into_iter(),
+        }
+    }
+
+    /// Creates a consuming iterator yielding the map's values in arbitrary order.
+    ///
+    /// The map cannot be used after calling this. The iterator's item type is `V`.
+    pub fn into_values(self) -> IntoValues<K, V> {
+        IntoValues {
+            iter: self.storage.into_iter(),
+        }
+    }
+
+    /// Returns a cursor pointing at the map's first key-value pair.
+    ///
+    /// The cursor points past the end if the map is empty.
+    pub fn cursor(&self) -> Cursor<'_, K, V> {
+        Cursor {
+            map: self,
+            index: 0,
+        }
+    }
+
+    /// Returns a cursor pointing at the map's first key-value pair that can remove and insert
+    /// pairs as it moves through the map.
+    ///
+    /// The cursor points past the end if the map is empty.
+    pub fn cursor_mut(&mut self) -> CursorMut<'_, K, V> {
+        CursorMut {
+            map: self,
+            index: 0,
+        }
+    }
+
+    /// Returns a view of the map's key-value pairs as a slice.
+    pub fn as_slice(&self) -> &Slice<K, V> {
+        Slice::from_slice(&self.storage)
+    }
+
+    /// Returns a view of the map's key-value pairs as a mutable slice.
+    ///
+    /// The slice only allows mutating the values, not the keys.
+    pub fn as_mut_slice(&mut self) -> &mut Slice<K, V> {
+        Slice::from_mut_slice(&mut self.storage)
+    }
+
     /// Returns a reference to the value in the map whose key is equal to the given key.
     ///
     /// Returns `None` if the map contains no such key.
@@ -234,6 +609,19 @@ impl<K: Eq, V> LinearMap<K, V> {
         None
     }
 
+    /// Returns references to the key-value pair in the map whose key is equal to the given key.
+    ///
+    /// Returns `None` if the map contains no such key.
+    ///
+    /// The given key may be any borrowed form of the map's key type, but `Eq` on the borrowed form
+    /// *must* match that of the key type.
+    pub fn get_key_value<Q: ?Sized + Eq>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+    {
+        self.iter().find(|&(k, _)| key == k.borrow())
+    }
+
     /// Returns a mutable reference to the value in the map whose key is equal to the given key.
     ///
     /// Returns `None` if the map contains no such key.
@@ -283,6 +671,36 @@ impl<K: Eq, V> LinearMap<K, V> {
         }
     }
 
+    /// Inserts a key-value pair into the map without checking whether it already contains an
+    /// equal key, and returns a mutable reference to the value.
+    ///
+    /// This runs in `O(1)` amortized time. It is a logic error for the map to already contain a
+    /// key that is equal to the given key; this is only checked in debug builds. The behavior
+    /// resulting from such a logic error is not specified, but will not result in undefined
+    /// behavior.
+    pub fn insert_unique_unchecked(&mut self, key: K, value: V) -> &mut V {
+        debug_assert!(!self.contains_key(&key), "map already contains the key");
+        self.storage.push((key, value));
+        &mut self.storage.last_mut().unwrap().1
+    }
+
+    /// Inserts the key-value pairs yielded by the given iterator without checking whether the
+    /// map already contains equal keys.
+    ///
+    /// This runs in `O(m)` amortized time. It is a logic error for the map or the iterator to
+    /// contain two equal keys; see
+    /// [`insert_unique_unchecked`](#method.insert_unique_unchecked).
+    pub fn extend_unique_unchecked<I>(&mut self, key_values: I)
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        let iter = key_values.into_iter();
+        self.storage.reserve(iter.size_hint().0);
+        for (key, value) in iter {
+            self.insert_unique_unchecked(key, value);
+        }
+    }
+
     /// Removes the key in the map that is equal to the given key and returns its corresponding
     /// value.
     ///
@@ -291,68 +709,339 @@ impl<K: Eq, V> LinearMap<K, V> {
     /// The given key may be any borrowed form of the map's key type, but `Eq` on the borrowed form
     /// *must* match that of the key type.
     pub fn remove<Q: ?Sized + Eq>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+    {
+        self.remove_entry(key).map(|(_, v)| v)
+    }
+
+    /// Removes the key in the map that is equal to the given key and returns it along with its
+    /// corresponding value.
+    ///
+    /// Returns `None` if the map contained no such key.
+    ///
+    /// The given key may be any borrowed form of the map's key type, but `Eq` on the borrowed form
+    /// *must* match that of the key type.
+    pub fn remove_entry<Q: ?Sized + Eq>(&mut self, key: &Q) -> Option<(K, V)>
     where
         K: Borrow<Q>,
     {
         for i in 0..self.storage.len() {
             if self.storage[i].0.borrow() == key {
-                return Some(self.storage.swap_remove(i).1);
+                return Some(self.storage.swap_remove(i));
             }
         }
         None
     }
 
-    /// Returns the given key's corresponding entry in the map for in-place manipulation.
-    pub fn entry(&mut self, key: K) -> Entry<K, V> {
-        match self.storage.iter().position(|&(ref k, _)| key == *k) {
-            None => Vacant(VacantEntry { map: self, key }),
-            Some(index) => Occupied(OccupiedEntry { map: self, index }),
+    /// Applies the given function to the value in the map whose key is equal to the given key,
+    /// and returns a mutable reference to the updated value.
+    ///
+    /// Returns `None` without calling the function if the map contains no such key.
+    ///
+    /// The given key may be any borrowed form of the map's key type, but `Eq` on the borrowed form
+    /// *must* match that of the key type.
+    pub fn update<Q: ?Sized + Eq, F>(&mut self, key: &Q, f: F) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        F: FnOnce(&mut V),
+    {
+        let value = self.get_mut(key)?;
+        f(value);
+        Some(value)
+    }
+
+    /// Applies `update_fn` to the value in the map whose key is equal to the given key, or
+    /// inserts the result of `insert_fn` if the map contains no such key.
+    ///
+    /// Returns a mutable reference to the updated or inserted value.
+    pub fn upsert<F, G>(&mut self, key: K, insert_fn: F, update_fn: G) -> &mut V
+    where
+        F: FnOnce() -> V,
+        G: FnOnce(&mut V),
+    {
+        self.entry(key)
+            .and_modify(update_fn)
+            .or_insert_with(insert_fn)
+    }
+
+    /// Inserts, replaces or removes the value for the given key, according to the result of the
+    /// given function.
+    ///
+    /// The function receives the value in the map whose key is equal to the given key, or
+    /// `None` if the map contains no such key. If it returns `Some`, the returned value is
+    /// stored under the key. If it returns `None`, the key is removed from the map. The map is
+    /// only scanned once.
+    ///
+    /// Returns a mutable reference to the stored value, or `None` if the key was removed or not
+    /// inserted.
+    ///
+    /// The key keeps its position while the function runs. If the function panics, the value it
+    /// was given is lost, so the key is removed from the map; the other key-value pairs keep
+    /// their order.
+    pub fn alter<F>(&mut self, key: K, f: F) -> Option<&mut V>
+    where
+        F: FnOnce(Option<V>) -> Option<V>,
+    {
+        match self.entry(key) {
+            Occupied(OccupiedEntry { map, index }) => {
+                if map.replace_value_with(index, |_, value| f(Some(value))) {
+                    Some(&mut map.storage[index].1)
+                } else {
+                    None
+                }
+            }
+            Vacant(entry) => f(None).map(|value| entry.insert(value)),
         }
     }
-}
 
-impl<K: Clone, V: Clone> Clone for LinearMap<K, V> {
-    fn clone(&self) -> Self {
-        LinearMap {
-            storage: self.storage.clone(),
+    /// Replaces the value at the given position with the result of `f`, which receives the key
+    /// and the old value, and returns `true`. If `f` returns `None`, the key-value pair is
+    /// removed instead and `false` is returned.
+    ///
+    /// If `f` panics, the pair is removed and the other pairs keep their order.
+    fn replace_value_with<F>(&mut self, index: usize, f: F) -> bool
+    where
+        F: FnOnce(&K, V) -> Option<V>,
+    {
+        // SAFETY: `index` is in bounds, and until the value is written back `hole` removes the
+        // pair without dropping the moved-out value.
+        let value = unsafe { ptr::read(&self.storage[index].1) };
+        let hole = Hole {
+            storage: &mut self.storage,
+            index,
+        };
+        match f(&hole.storage[index].0, value) {
+            Some(value) => {
+                unsafe { ptr::write(&mut hole.storage[index].1, value) };
+                mem::forget(hole);
+                true
+            }
+            None => false,
         }
     }
 
-    fn clone_from(&mut self, other: &Self) {
-        self.storage.clone_from(&other.storage);
+    /// Inserts the key-value pairs yielded by the given iterator into the map.
+    ///
+    /// Returns the pairs whose values were displaced, in iteration order: each consists of the
+    /// given key and the value it replaced. As with [`insert`](#method.insert), the keys already
+    /// in the map are not updated.
+    ///
+    /// This runs in `O(n·m)` time; see [`insert_many_hashed`](#method.insert_many_hashed) and
+    /// [`insert_many_sorted`](#method.insert_many_sorted) for large batches.
+    pub fn insert_many<I>(&mut self, key_values: I) -> Vec<(K, V)>
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        let iter = key_values.into_iter();
+        self.storage.reserve(iter.size_hint().0);
+        let mut displaced = vec![];
+        for (key, value) in iter {
+            match self.storage.iter().position(|e| e.0 == key) {
+                Some(index) => {
+                    let old = mem::replace(&mut self.storage[index].1, value);
+                    displaced.push((key, old));
+                }
+                None => self.storage.push((key, value)),
+            }
+        }
+        displaced
     }
-}
 
-impl<K: Eq + Debug, V: Debug> Debug for LinearMap<K, V> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.debug_map().entries(self).finish()
+    /// Like [`insert_many`](#method.insert_many), but looks the keys up in a temporary hash
+    /// table, which runs in `O(n + m)` expected time.
+    pub fn insert_many_hashed<I>(&mut self, key_values: I) -> Vec<(K, V)>
+    where
+        K: Hash,
+        I: IntoIterator<Item = (K, V)>,
+    {
+        let batch: Vec<_> = key_values.into_iter().collect();
+        let mut index = HashMap::new();
+        let targets = batch_positions(&self.storage, &batch, |key, next| {
+            *index.entry(key).or_insert(next)
+        });
+        self.insert_at_positions(batch, targets)
     }
-}
 
-impl<K: Eq, V> Default for LinearMap<K, V> {
-    fn default() -> Self {
-        Self::new()
+    /// Like [`insert_many`](#method.insert_many), but looks the keys up in a temporary sorted
+    /// index, which runs in `O((n + m) log(n + m))` time.
+    pub fn insert_many_sorted<I>(&mut self, key_values: I) -> Vec<(K, V)>
+    where
+        K: Ord,
+        I: IntoIterator<Item = (K, V)>,
+    {
+        let batch: Vec<_> = key_values.into_iter().collect();
+        let mut index = BTreeMap::new();
+        let targets = batch_positions(&self.storage, &batch, |key, next| {
+            *index.entry(key).or_insert(next)
+        });
+        self.insert_at_positions(batch, targets)
     }
-}
 
-impl<K: Eq, V> Extend<(K, V)> for LinearMap<K, V> {
-    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, key_values: I) {
-        for (key, value) in key_values {
-            self.insert(key, value);
+    fn insert_at_positions(&mut self, batch: Vec<(K, V)>, targets: Vec<usize>) -> Vec<(K, V)> {
+        let mut displaced = vec![];
+        for ((key, value), target) in batch.into_iter().zip(targets) {
+            if target < self.storage.len() {
+                let old = mem::replace(&mut self.storage[target].1, value);
+                displaced.push((key, old));
+            } else {
+                self.storage.push((key, value));
+            }
         }
+        displaced
     }
-}
 
-impl<K: Eq, V> iter::FromIterator<(K, V)> for LinearMap<K, V> {
-    fn from_iter<I: IntoIterator<Item = (K, V)>>(key_values: I) -> Self {
-        let mut map = Self::new();
-        map.extend(key_values);
-        map
+    /// Removes the keys in the map that are equal to any of the given keys, in a single scan
+    /// over the map.
+    ///
+    /// Returns the number of removed key-value pairs.
+    ///
+    /// This runs in `O(n·m)` time; see [`remove_all_hashed`](#method.remove_all_hashed) and
+    /// [`remove_all_sorted`](#method.remove_all_sorted) for large batches.
+    ///
+    /// The given keys may be any borrowed form of the map's key type, but `Eq` on the borrowed
+    /// form *must* match that of the key type.
+    pub fn remove_all<'q, Q, I>(&mut self, keys: I) -> usize
+    where
+        Q: ?Sized + Eq + 'q,
+        K: Borrow<Q>,
+        I: IntoIterator<Item = &'q Q>,
+    {
+        let keys: Vec<&Q> = keys.into_iter().collect();
+        self.remove_keys_where(|key| keys.contains(&key))
     }
-}
 
-impl<'a, K: Eq + Borrow<Q>, V, Q: ?Sized + Eq> ops::Index<&'a Q> for LinearMap<K, V> {
-    type Output = V;
+    /// Like [`remove_all`](#method.remove_all), but looks the keys up in a temporary hash
+    /// table, which runs in `O(n + m)` expected time.
+    pub fn remove_all_hashed<'q, Q, I>(&mut self, keys: I) -> usize
+    where
+        Q: ?Sized + Hash + Eq + 'q,
+        K: Borrow<Q>,
+        I: IntoIterator<Item = &'q Q>,
+    {
+        let keys: HashSet<&Q> = keys.into_iter().collect();
+        self.remove_keys_where(|key| keys.contains(key))
+    }
+
+    /// Like [`remove_all`](#method.remove_all), but looks the keys up in a temporary sorted
+    /// index, which runs in `O((n + m) log m)` time.
+    pub fn remove_all_sorted<'q, Q, I>(&mut self, keys: I) -> usize
+    where
+        Q: ?Sized + Ord + 'q,
+        K: Borrow<Q>,
+        I: IntoIterator<Item = &'q Q>,
+    {
+        let keys: BTreeSet<&Q> = keys.into_iter().collect();
+        self.remove_keys_where(|key| keys.contains(key))
+    }
+
+    fn remove_keys_where<Q, F>(&mut self, mut pred: F) -> usize
+    where
+        Q: ?Sized,
+        K: Borrow<Q>,
+        F: FnMut(&Q) -> bool,
+    {
+        let len = self.len();
+        self.retain(|k, _| !pred(k.borrow()));
+        len - self.len()
+    }
+
+    /// Keeps only the key-value pairs whose keys are contained in the given set, in a single
+    /// scan over the map.
+    pub fn retain_keys(&mut self, keys: &LinearSet<K>) {
+        self.retain(|k, _| keys.contains(k));
+    }
+
+    /// Checks if the map is equal to `other`, like `==`, but looks the keys up in a temporary
+    /// hash table, which runs in `O(n)` expected time instead of `O(n²)`.
+    pub fn eq_hashed(&self, other: &Self) -> bool
+    where
+        K: Hash,
+        V: PartialEq,
+    {
+        if self.len() != other.len() {
+            return false;
+        }
+        let other: HashMap<&K, &V> = other.iter().collect();
+        self.iter().all(|(k, v)| other.get(k) == Some(&v))
+    }
+
+    /// Checks if the map is equal to `other`, like `==`, but compares the key-value pairs in
+    /// key order, which runs in `O(n log n)` time instead of `O(n²)`.
+    pub fn eq_sorted(&self, other: &Self) -> bool
+    where
+        K: Ord,
+        V: PartialEq,
+    {
+        self.len() == other.len() && self.sorted_iter().eq(other.sorted_iter())
+    }
+
+    /// Returns the given key's corresponding entry in the map for in-place manipulation.
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
+        match self.storage.iter().position(|(k, _)| key == *k) {
+            None => Vacant(VacantEntry { map: self, key }),
+            Some(index) => Occupied(OccupiedEntry { map: self, index }),
+        }
+    }
+}
+
+impl<K: Eq, K2: Eq, V> LinearMap<K, LinearMap<K2, V>> {
+    /// Moves all entries from `other` into `self`, merging the inner maps of keys that are
+    /// present in both.
+    ///
+    /// When an inner key is present in both inner maps, the value from `other` replaces the value
+    /// in `self`, as with [`append`](#method.append). This suits layered configuration, where
+    /// `other` is the layer with the higher precedence.
+    pub fn deep_merge(&mut self, other: Self) {
+        self.merge_with(other, |_, mut existing, mut incoming| {
+            existing.append(&mut incoming);
+            existing
+        });
+    }
+}
+
+impl<K: Clone, V: Clone> Clone for LinearMap<K, V> {
+    fn clone(&self) -> Self {
+        LinearMap {
+            storage: self.storage.clone(),
+        }
+    }
+
+    fn clone_from(&mut self, other: &Self) {
+        self.storage.clone_from(&other.storage);
+    }
+}
+
+impl<K: Eq + Debug, V: Debug> Debug for LinearMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self).finish()
+    }
+}
+
+impl<K: Eq, V> Default for LinearMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Eq, V> Extend<(K, V)> for LinearMap<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, key_values: I) {
+        for (key, value) in key_values {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K: Eq, V> iter::FromIterator<(K, V)> for LinearMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(key_values: I) -> Self {
+        let mut map = Self::new();
+        map.extend(key_values);
+        map
+    }
+}
+
+impl<'a, K: Eq + Borrow<Q>, V, Q: ?Sized + Eq> ops::Index<&'a Q> for LinearMap<K, V> {
+    type Output = V;
 
     fn index(&self, key: &'a Q) -> &V {
         self.get(key).expect("key not found")
@@ -377,6 +1066,88 @@ impl<K: Eq, V: PartialEq> PartialEq for LinearMap<K, V> {
 
 impl<K: Eq, V: Eq> Eq for LinearMap<K, V> {}
 
+impl<K, V, S> PartialEq<HashMap<K, V, S>> for LinearMap<K, V>
+where
+    K: Eq + Hash,
+    V: PartialEq,
+    S: BuildHasher,
+{
+    fn eq(&self, other: &HashMap<K, V, S>) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .all(|(key, value)| other.get(key) == Some(value))
+    }
+}
+
+impl<K, V, S> PartialEq<LinearMap<K, V>> for HashMap<K, V, S>
+where
+    K: Eq + Hash,
+    V: PartialEq,
+    S: BuildHasher,
+{
+    fn eq(&self, other: &LinearMap<K, V>) -> bool {
+        other == self
+    }
+}
+
+impl<K: Ord, V: PartialEq> PartialEq<BTreeMap<K, V>> for LinearMap<K, V> {
+    fn eq(&self, other: &BTreeMap<K, V>) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .all(|(key, value)| other.get(key) == Some(value))
+    }
+}
+
+impl<K: Ord, V: PartialEq> PartialEq<LinearMap<K, V>> for BTreeMap<K, V> {
+    fn eq(&self, other: &LinearMap<K, V>) -> bool {
+        other == self
+    }
+}
+
+impl<K: Eq + Hash, V: Hash> Hash for LinearMap<K, V> {
+    /// Hashes the map independently of the order of its key-value pairs, so that maps that
+    /// compare equal also hash equal.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len());
+        state.write_u64(unordered_hash(self));
+    }
+}
+
+impl<K: Ord, V: PartialOrd> PartialOrd for LinearMap<K, V> {
+    /// Compares the maps' key-value pairs lexicographically in key order, independently of the
+    /// order they are stored in.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.sorted_iter().partial_cmp(other.sorted_iter())
+    }
+}
+
+impl<K: Ord, V: Ord> Ord for LinearMap<K, V> {
+    /// Compares the maps' key-value pairs lexicographically in key order, independently of the
+    /// order they are stored in.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.sorted_iter().cmp(other.sorted_iter())
+    }
+}
+
+/// Combines the hashes of the given items commutatively, so that the result does not depend on
+/// their order.
+fn unordered_hash<I>(items: I) -> u64
+where
+    I: IntoIterator,
+    I::Item: Hash,
+{
+    items
+        .into_iter()
+        .map(|item| {
+            let mut hasher = DefaultHasher::new();
+            item.hash(&mut hasher);
+            hasher.finish()
+        })
+        .fold(0, u64::wrapping_add)
+}
+
 impl<K: Eq, V> From<LinearMap<K, V>> for Vec<(K, V)> {
     fn from(other: LinearMap<K, V>) -> Self {
         other.storage
@@ -384,11 +1155,380 @@ impl<K: Eq, V> From<LinearMap<K, V>> for Vec<(K, V)> {
 }
 
 impl<K: Eq, V> From<Vec<(K, V)>> for LinearMap<K, V> {
+    /// Creates a map from a vector of key-value pairs.
+    ///
+    /// It is a logic error for the vector to contain two pairs with equal keys. This is only
+    /// checked in debug builds; see
+    /// [`LinearMap::try_from_vec`](struct.LinearMap.html#method.try_from_vec) and
+    /// [`LinearMap::from_vec_dedup`](struct.LinearMap.html#method.from_vec_dedup) for vectors
+    /// that may contain duplicates.
     fn from(other: Vec<(K, V)>) -> Self {
+        debug_assert!(
+            first_duplicate(&other).is_none(),
+            "vector contains duplicate keys"
+        );
         Self { storage: other }
     }
 }
 
+impl<K: Eq, V, const N: usize> From<[(K, V); N]> for LinearMap<K, V> {
+    /// Creates a map from an array of key-value pairs.
+    ///
+    /// Pairs with duplicate keys are dropped as with
+    /// [`DedupPolicy::LastWins`](enum.DedupPolicy.html#variant.LastWins).
+    fn from(other: [(K, V); N]) -> Self {
+        Self::from_vec_dedup(Vec::from(other), DedupPolicy::LastWins)
+    }
+}
+
+impl<K: Eq, V, S> From<HashMap<K, V, S>> for LinearMap<K, V> {
+    /// Creates a map from a `HashMap`, in the `HashMap`'s iteration order.
+    fn from(other: HashMap<K, V, S>) -> Self {
+        LinearMap::from_unique(other)
+    }
+}
+
+impl<K: Eq, V> From<BTreeMap<K, V>> for LinearMap<K, V> {
+    /// Creates a map from a `BTreeMap`, with its pairs sorted by key.
+    fn from(other: BTreeMap<K, V>) -> Self {
+        LinearMap::from_unique(other)
+    }
+}
+
+impl<K, V, S> From<LinearMap<K, V>> for HashMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    fn from(other: LinearMap<K, V>) -> Self {
+        other.into_iter().collect()
+    }
+}
+
+impl<K: Ord, V> From<LinearMap<K, V>> for BTreeMap<K, V> {
+    fn from(other: LinearMap<K, V>) -> Self {
+        other.into_iter().collect()
+    }
+}
+
+/// The error returned when creating a `LinearMap` or `LinearSet` from a vector with duplicate
+/// keys.
+///
+/// See [`LinearMap::try_from_vec`](struct.LinearMap.html#method.try_from_vec) for details.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DuplicateKeyError {
+    first: usize,
+    second: usize,
+}
+
+impl DuplicateKeyError {
+    /// Returns the position of the first of the two pairs with equal keys.
+    pub fn first(&self) -> usize {
+        self.first
+    }
+
+    /// Returns the position of the second of the two pairs with equal keys.
+    pub fn second(&self) -> usize {
+        self.second
+    }
+}
+
+impl fmt::Display for DuplicateKeyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "duplicate keys at positions {} and {}",
+            self.first, self.second
+        )
+    }
+}
+
+impl Error for DuplicateKeyError {}
+
+/// Which of several key-value pairs with equal keys to keep.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DedupPolicy {
+    /// Keep the first pair.
+    FirstWins,
+
+    /// Keep the last pair.
+    LastWins,
+}
+
+/// A map from keys to values, implemented by `LinearMap`, `HashMap` and `BTreeMap`.
+///
+/// Code written against `MapLike` can be run with whichever map suits the expected number of
+/// entries. Methods that are also inherent methods of the maps behave the same.
+///
+/// Keys are looked up by a `&Q`, which defaults to `&K`. A map implements `MapLike<K, V, Q>` for
+/// any `Q` that its own lookups accept, so `MapLike<String, V, str>` looks keys up by `&str`.
+///
+/// # Examples
+///
+/// ```
+/// use linear_map::{LinearMap, MapLike};
+/// use std::collections::HashMap;
+///
+/// fn tally<M: MapLike<char, usize>>(map: &mut M, text: &str) {
+///     for c in text.chars() {
+///         *map.get_or_insert_with(c, || 0) += 1;
+///     }
+/// }
+///
+/// let mut linear = LinearMap::new();
+/// let mut hashed = HashMap::new();
+/// tally(&mut linear, "hello");
+/// tally(&mut hashed, "hello");
+/// assert_eq!(linear, hashed);
+/// ```
+pub trait MapLike<K, V, Q: ?Sized = K>: for<'a> MapLikeIter<'a, K, V> {
+    /// Returns the number of entries in the map.
+    fn len(&self) -> usize;
+
+    /// Checks if the map is empty.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Checks if the map contains the given key.
+    fn contains_key(&self, key: &Q) -> bool;
+
+    /// Returns a reference to the value mapped to the given key, if any.
+    fn get(&self, key: &Q) -> Option<&V>;
+
+    /// Returns a mutable reference to the value mapped to the given key, if any.
+    fn get_mut(&mut self, key: &Q) -> Option<&mut V>;
+
+    /// Maps the given key to the given value and returns the previous value, if any.
+    fn insert(&mut self, key: K, value: V) -> Option<V>;
+
+    /// Removes the given key from the map and returns its value, if any.
+    fn remove(&mut self, key: &Q) -> Option<V>;
+
+    /// Returns a mutable reference to the value mapped to the given key, first inserting the
+    /// result of `default` if the key is not in the map.
+    fn get_or_insert_with<F: FnOnce() -> V>(&mut self, key: K, default: F) -> &mut V;
+
+    /// Returns an iterator yielding references to the map's keys and their corresponding values.
+    fn iter(&self) -> <Self as MapLikeIter<'_, K, V>>::Iter;
+}
+
+/// The iterator type of a [`MapLike`](trait.MapLike.html) map borrowed for `'a`.
+///
+/// The `Bounds` parameter is only there to limit the `for<'a>` bound on `MapLike` to the
+/// lifetimes the map outlives, and should be left to its default.
+pub trait MapLikeIter<'a, K: 'a, V: 'a, Bounds = &'a Self> {
+    /// The iterator returned by [`MapLike::iter`](trait.MapLike.html#tymethod.iter).
+    type Iter: Iterator<Item = (&'a K, &'a V)>;
+}
+
+impl<'a, K: Eq, V> MapLikeIter<'a, K, V> for LinearMap<K, V> {
+    type Iter = Iter<'a, K, V>;
+}
+
+impl<K, V, Q> MapLike<K, V, Q> for LinearMap<K, V>
+where
+    K: Eq + Borrow<Q>,
+    Q: ?Sized + Eq,
+{
+    fn len(&self) -> usize {
+        LinearMap::len(self)
+    }
+
+    fn contains_key(&self, key: &Q) -> bool {
+        LinearMap::contains_key(self, key)
+    }
+
+    fn get(&self, key: &Q) -> Option<&V> {
+        LinearMap::get(self, key)
+    }
+
+    fn get_mut(&mut self, key: &Q) -> Option<&mut V> {
+        LinearMap::get_mut(self, key)
+    }
+
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        LinearMap::insert(self, key, value)
+    }
+
+    fn remove(&mut self, key: &Q) -> Option<V> {
+        LinearMap::remove(self, key)
+    }
+
+    fn get_or_insert_with<F: FnOnce() -> V>(&mut self, key: K, default: F) -> &mut V {
+        self.entry(key).or_insert_with(default)
+    }
+
+    fn iter(&self) -> Iter<'_, K, V> {
+        LinearMap::iter(self)
+    }
+}
+
+impl<'a, K: Eq + Hash, V, S: BuildHasher> MapLikeIter<'a, K, V> for HashMap<K, V, S> {
+    type Iter = hash_map::Iter<'a, K, V>;
+}
+
+impl<K, V, Q, S> MapLike<K, V, Q> for HashMap<K, V, S>
+where
+    K: Eq + Hash + Borrow<Q>,
+    Q: ?Sized + Eq + Hash,
+    S: BuildHasher,
+{
+    fn len(&self) -> usize {
+        HashMap::len(self)
+    }
+
+    fn contains_key(&self, key: &Q) -> bool {
+        HashMap::contains_key(self, key)
+    }
+
+    fn get(&self, key: &Q) -> Option<&V> {
+        HashMap::get(self, key)
+    }
+
+    fn get_mut(&mut self, key: &Q) -> Option<&mut V> {
+        HashMap::get_mut(self, key)
+    }
+
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        HashMap::insert(self, key, value)
+    }
+
+    fn remove(&mut self, key: &Q) -> Option<V> {
+        HashMap::remove(self, key)
+    }
+
+    fn get_or_insert_with<F: FnOnce() -> V>(&mut self, key: K, default: F) -> &mut V {
+        self.entry(key).or_insert_with(default)
+    }
+
+    fn iter(&self) -> hash_map::Iter<'_, K, V> {
+        HashMap::iter(self)
+    }
+}
+
+impl<'a, K: Ord, V> MapLikeIter<'a, K, V> for BTreeMap<K, V> {
+    type Iter = btree_map::Iter<'a, K, V>;
+}
+
+impl<K, V, Q> MapLike<K, V, Q> for BTreeMap<K, V>
+where
+    K: Ord + Borrow<Q>,
+    Q: ?Sized + Ord,
+{
+    fn len(&self) -> usize {
+        BTreeMap::len(self)
+    }
+
+    fn contains_key(&self, key: &Q) -> bool {
+        BTreeMap::contains_key(self, key)
+    }
+
+    fn get(&self, key: &Q) -> Option<&V> {
+        BTreeMap::get(self, key)
+    }
+
+    fn get_mut(&mut self, key: &Q) -> Option<&mut V> {
+        BTreeMap::get_mut(self, key)
+    }
+
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        BTreeMap::insert(self, key, value)
+    }
+
+    fn remove(&mut self, key: &Q) -> Option<V> {
+        BTreeMap::remove(self, key)
+    }
+
+    fn get_or_insert_with<F: FnOnce() -> V>(&mut self, key: K, default: F) -> &mut V {
+        self.entry(key).or_insert_with(default)
+    }
+
+    fn iter(&self) -> btree_map::Iter<'_, K, V> {
+        BTreeMap::iter(self)
+    }
+}
+
+/// Returns the positions of the first two pairs with equal keys.
+fn first_duplicate<K: Eq, V>(storage: &[(K, V)]) -> Option<(usize, usize)> {
+    (1..storage.len()).find_map(|second| {
+        storage[..second]
+            .iter()
+            .position(|e| e.0 == storage[second].0)
+            .map(|first| (first, second))
+    })
+}
+
+/// A key-value pair whose value has been moved out, which is removed from the storage when the
+/// `Hole` is dropped.
+struct Hole<'a, K: 'a, V: 'a> {
+    storage: &'a mut Vec<(K, V)>,
+    index: usize,
+}
+
+impl<'a, K, V> Drop for Hole<'a, K, V> {
+    fn drop(&mut self) {
+        let len = self.storage.len();
+        // SAFETY: the pair at `index` is in bounds and its value has been moved out, so only its
+        // key is dropped, after the following pairs have been shifted over it.
+        unsafe {
+            let pair = self.storage.as_mut_ptr().add(self.index);
+            let key = ptr::read(&(*pair).0);
+            ptr::copy(pair.add(1), pair, len - self.index - 1);
+            self.storage.set_len(len - 1);
+            drop(key);
+        }
+    }
+}
+
+/// Removes the pairs with duplicate keys in place, keeping the relative order of the rest.
+fn dedup<K: Eq, V>(storage: &mut Vec<(K, V)>, policy: DedupPolicy) {
+    // `storage[..len]` holds the deduplicated pairs, `storage[len..i]` those to be dropped.
+    let mut len = 0;
+    for i in 0..storage.len() {
+        match storage[..len].iter().position(|e| e.0 == storage[i].0) {
+            Some(j) => {
+                if policy == DedupPolicy::LastWins {
+                    storage.swap(j, i);
+                }
+            }
+            None => {
+                storage.swap(len, i);
+                len += 1;
+            }
+        }
+    }
+    storage.truncate(len);
+}
+
+/// Returns the position each pair of `batch` ends up at when inserted into `storage` in order.
+///
+/// `position` returns the position recorded for the given key in a temporary index, first
+/// recording the given one if there is none.
+fn batch_positions<'a, K, V, F>(
+    storage: &'a [(K, V)],
+    batch: &'a [(K, V)],
+    mut position: F,
+) -> Vec<usize>
+where
+    F: FnMut(&'a K, usize) -> usize,
+{
+    for (i, e) in storage.iter().enumerate() {
+        position(&e.0, i);
+    }
+    let mut next = storage.len();
+    batch
+        .iter()
+        .map(|e| {
+            let target = position(&e.0, next);
+            if target == next {
+                next += 1;
+            }
+            target
+        })
+        .collect()
+}
+
 /// Creates a `LinearMap` from a list of key-value pairs.
 ///
 /// The created `LinearMap` has a capacity set to the number of entries provided.
@@ -423,90 +1563,684 @@ macro_rules! linear_map {
     };
 }
 
-/// A view into a single occupied location in a `LinearMap`.
-///
-/// See [`LinearMap::entry`](struct.LinearMap.html#method.entry) for details.
-pub struct OccupiedEntry<'a, K: 'a, V: 'a> {
-    map: &'a mut LinearMap<K, V>,
-    index: usize,
-}
+/// A view into a single occupied location in a `LinearMap`.
+///
+/// See [`LinearMap::entry`](struct.LinearMap.html#method.entry) for details.
+pub struct OccupiedEntry<'a, K: 'a, V: 'a> {
+    map: &'a mut LinearMap<K, V>,
+    index: usize,
+}
+
+/// A view into a single vacant location in a `LinearMap`.
+///
+/// See [`LinearMap::entry`](struct.LinearMap.html#method.entry) for details.
+pub struct VacantEntry<'a, K: 'a, V: 'a> {
+    map: &'a mut LinearMap<K, V>,
+    key: K,
+}
+
+/// A view into a single entry in a `LinearMap`.
+///
+/// See [`LinearMap::entry`](struct.LinearMap.html#method.entry) for details.
+pub enum Entry<'a, K: 'a, V: 'a> {
+    /// An occupied entry.
+    Occupied(OccupiedEntry<'a, K, V>),
+
+    /// A vacant entry.
+    Vacant(VacantEntry<'a, K, V>),
+}
+
+impl<'a, K, V> Entry<'a, K, V> {
+    /// Ensures that the entry is occupied by inserting the given value if it is vacant.
+    ///
+    /// Returns a mutable reference to the entry's value.
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        match self {
+            Occupied(entry) => entry.into_mut(),
+            Vacant(entry) => entry.insert(default),
+        }
+    }
+
+    /// Ensures that the entry is occupied by inserting the the result of the given function if it
+    /// is vacant.
+    ///
+    /// Returns a mutable reference to the entry's value.
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Occupied(entry) => entry.into_mut(),
+            Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Applies the given function to the entry's value if it is occupied.
+    ///
+    /// Returns the entry for further manipulation.
+    pub fn and_modify<F: FnOnce(&mut V)>(self, f: F) -> Self {
+        match self {
+            Occupied(mut entry) => {
+                f(entry.get_mut());
+                Occupied(entry)
+            }
+            Vacant(entry) => Vacant(entry),
+        }
+    }
+}
+
+impl<'a, K, V> OccupiedEntry<'a, K, V> {
+    /// Returns a reference to the entry's key.
+    pub fn key(&self) -> &K {
+        &self.map.storage[self.index].0
+    }
+
+    /// Returns a reference to the entry's value.
+    pub fn get(&self) -> &V {
+        &self.map.storage[self.index].1
+    }
+
+    /// Returns a mutable reference to the entry's value.
+    pub fn get_mut(&mut self) -> &mut V {
+        &mut self.map.storage[self.index].1
+    }
+
+    /// Returns a mutable reference to the entry's value with the same lifetime as the map.
+    pub fn into_mut(self) -> &'a mut V {
+        &mut self.map.storage[self.index].1
+    }
+
+    /// Replaces the entry's value with the given one and returns the previous value.
+    pub fn insert(&mut self, value: V) -> V {
+        mem::replace(self.get_mut(), value)
+    }
+
+    /// Removes the entry from the map and returns its value.
+    pub fn remove(self) -> V {
+        self.remove_entry().1
+    }
+
+    /// Removes the entry from the map and returns its key and value.
+    pub fn remove_entry(self) -> (K, V) {
+        self.map.storage.swap_remove(self.index)
+    }
+}
+
+impl<'a, K, V> VacantEntry<'a, K, V> {
+    /// Returns a reference to the key that would be used when inserting a value.
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Inserts the entry into the map with the given value.
+    ///
+    /// Returns a mutable reference to the entry's value with the same lifetime as the map.
+    pub fn insert(self, value: V) -> &'a mut V {
+        self.insert_entry(value).into_mut()
+    }
+
+    /// Inserts the entry into the map with the given value.
+    ///
+    /// Returns the now occupied entry.
+    pub fn insert_entry(self, value: V) -> OccupiedEntry<'a, K, V> {
+        self.map.storage.push((self.key, value));
+        let index = self.map.storage.len() - 1;
+        OccupiedEntry {
+            map: self.map,
+            index,
+        }
+    }
+
+    /// Takes ownership of the key that would be used when inserting a value.
+    pub fn into_key(self) -> K {
+        self.key
+    }
+}
+
+impl<'a, K: Debug, V: Debug> Debug for Entry<'a, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Occupied(ref entry) => f.debug_tuple("Entry").field(entry).finish(),
+            Vacant(ref entry) => f.debug_tuple("Entry").field(entry).finish(),
+        }
+    }
+}
+
+impl<'a, K: Debug, V: Debug> Debug for OccupiedEntry<'a, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("OccupiedEntry")
+            .field("key", self.key())
+            .field("value", self.get())
+            .finish()
+    }
+}
+
+impl<'a, K: Debug, V> Debug for VacantEntry<'a, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("VacantEntry").field(self.key()).finish()
+    }
+}
+
+/// A view of a `LinearMap`'s keys as a set.
+///
+/// `KeySet` implements [`SetLike`](set/trait.SetLike.html), so it can also be the other operand
+/// of [`LinearSet`](set/struct.LinearSet.html)'s set operations.
+///
+/// See [`LinearMap::key_set`](struct.LinearMap.html#method.key_set) for details.
+pub struct KeySet<'a, K: 'a, V: 'a> {
+    map: &'a LinearMap<K, V>,
+}
+
+impl<'a, K, V> Clone for KeySet<'a, K, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, K, V> Copy for KeySet<'a, K, V> {}
+
+impl<'a, K: Eq, V> KeySet<'a, K, V> {
+    /// Returns the number of keys in the set.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Checks if the set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns an iterator yielding references to the keys in arbitrary order.
+    pub fn iter(&self) -> Keys<'a, K, V> {
+        self.map.keys()
+    }
+
+    /// Checks if the set contains a key that is equal to the given key.
+    ///
+    /// The given key may be any borrowed form of the map's key type, but `Eq` on the borrowed form
+    /// *must* match that of the key type.
+    pub fn contains<Q: ?Sized + Eq>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        self.map.contains_key(key)
+    }
+
+    /// Checks if every key in the set is contained in `other`.
+    pub fn is_subset<S: SetLike<K> + ?Sized>(&self, other: &S) -> bool {
+        self.iter().all(|k| other.contains(k))
+    }
+
+    /// Checks if no key in the set is contained in `other`.
+    pub fn is_disjoint<S: SetLike<K> + ?Sized>(&self, other: &S) -> bool {
+        self.iter().all(|k| !other.contains(k))
+    }
+
+    /// Returns an iterator yielding the keys in the set that are also contained in `other`.
+    pub fn intersection<'b, S>(&self, other: &'b S) -> impl Iterator<Item = &'a K> + 'b
+    where
+        'a: 'b,
+        S: SetLike<K> + ?Sized,
+    {
+        self.iter().filter(move |k| other.contains(k))
+    }
+
+    /// Returns an iterator yielding the keys in the set that are not contained in `other`.
+    pub fn difference<'b, S>(&self, other: &'b S) -> impl Iterator<Item = &'a K> + 'b
+    where
+        'a: 'b,
+        S: SetLike<K> + ?Sized,
+    {
+        self.iter().filter(move |k| !other.contains(k))
+    }
+
+    /// Returns an iterator yielding the keys in the set, followed by the values yielded by
+    /// `other` that are not in the set.
+    ///
+    /// `other` may be another `KeySet` or a reference to a `LinearSet`, for example.
+    pub fn union<'b, I>(&self, other: I) -> impl Iterator<Item = &'b K> + 'b
+    where
+        'a: 'b,
+        I: IntoIterator<Item = &'b K>,
+        I::IntoIter: 'b,
+    {
+        let map: &'b LinearMap<K, V> = self.map;
+        let rest = other.into_iter().filter(move |k| !map.contains_key(*k));
+        map.keys().chain(rest)
+    }
+}
+
+impl<'a, K: Eq, V> IntoIterator for KeySet<'a, K, V> {
+    type Item = &'a K;
+    type IntoIter = Keys<'a, K, V>;
+
+    fn into_iter(self) -> Keys<'a, K, V> {
+        self.iter()
+    }
+}
+
+impl<'a, K: Debug, V> Debug for KeySet<'a, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set()
+            .entries(self.map.storage.iter().map(|e| &e.0))
+            .finish()
+    }
+}
+
+/// A cursor over a `LinearMap`'s key-value pairs.
+///
+/// The cursor points either at a key-value pair or past the last pair.
+///
+/// See [`LinearMap::cursor`](struct.LinearMap.html#method.cursor) for details.
+pub struct Cursor<'a, K: 'a, V: 'a> {
+    map: &'a LinearMap<K, V>,
+    index: usize,
+}
+
+/// A cursor over a `LinearMap`'s key-value pairs that can remove and insert pairs.
+///
+/// The cursor points either at a key-value pair or past the last pair.
+///
+/// See [`LinearMap::cursor_mut`](struct.LinearMap.html#method.cursor_mut) for details.
+pub struct CursorMut<'a, K: 'a, V: 'a> {
+    map: &'a mut LinearMap<K, V>,
+    index: usize,
+}
+
+impl<'a, K, V> Cursor<'a, K, V> {
+    /// Returns the position the cursor points at.
+    ///
+    /// This is equal to the map's length if the cursor points past the end.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the key-value pair the cursor points at, or `None` if it points past the end.
+    pub fn current(&self) -> Option<(&'a K, &'a V)> {
+        self.map.storage.get(self.index).map(|e| (&e.0, &e.1))
+    }
+
+    /// Returns the key the cursor points at, or `None` if it points past the end.
+    pub fn key(&self) -> Option<&'a K> {
+        self.current().map(|e| e.0)
+    }
+
+    /// Returns the value the cursor points at, or `None` if it points past the end.
+    pub fn value(&self) -> Option<&'a V> {
+        self.current().map(|e| e.1)
+    }
+
+    /// Moves the cursor to the next key-value pair.
+    ///
+    /// Does nothing if the cursor already points past the end.
+    pub fn move_next(&mut self) {
+        if self.index < self.map.storage.len() {
+            self.index += 1;
+        }
+    }
+
+    /// Moves the cursor to the previous key-value pair.
+    ///
+    /// Does nothing if the cursor already points at the first pair.
+    pub fn move_prev(&mut self) {
+        if self.index > 0 {
+            self.index -= 1;
+        }
+    }
+}
+
+impl<'a, K, V> Clone for Cursor<'a, K, V> {
+    fn clone(&self) -> Self {
+        Cursor {
+            map: self.map,
+            index: self.index,
+        }
+    }
+}
+
+impl<'a, K, V> CursorMut<'a, K, V> {
+    /// Returns the position the cursor points at.
+    ///
+    /// This is equal to the map's length if the cursor points past the end.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the key-value pair the cursor points at, or `None` if it points past the end.
+    pub fn current(&self) -> Option<(&K, &V)> {
+        self.map.storage.get(self.index).map(|e| (&e.0, &e.1))
+    }
+
+    /// Returns the key the cursor points at, or `None` if it points past the end.
+    pub fn key(&self) -> Option<&K> {
+        self.current().map(|e| e.0)
+    }
+
+    /// Returns the value the cursor points at, or `None` if it points past the end.
+    pub fn value(&self) -> Option<&V> {
+        self.current().map(|e| e.1)
+    }
+
+    /// Returns a mutable reference to the value the cursor points at, or `None` if it points
+    /// past the end.
+    pub fn value_mut(&mut self) -> Option<&mut V> {
+        self.map.storage.get_mut(self.index).map(|e| &mut e.1)
+    }
+
+    /// Moves the cursor to the next key-value pair.
+    ///
+    /// Does nothing if the cursor already points past the end.
+    pub fn move_next(&mut self) {
+        if self.index < self.map.storage.len() {
+            self.index += 1;
+        }
+    }
+
+    /// Moves the cursor to the previous key-value pair.
+    ///
+    /// Does nothing if the cursor already points at the first pair.
+    pub fn move_prev(&mut self) {
+        if self.index > 0 {
+            self.index -= 1;
+        }
+    }
+
+    /// Removes the key-value pair the cursor points at and returns it, or returns `None` if
+    /// the cursor points past the end.
+    ///
+    /// Like [`LinearMap::remove`](struct.LinearMap.html#method.remove), this moves the map's
+    /// last pair into the vacated position, and the cursor then points at that pair.
+    pub fn remove_current(&mut self) -> Option<(K, V)> {
+        if self.index < self.map.storage.len() {
+            Some(self.map.storage.swap_remove(self.index))
+        } else {
+            None
+        }
+    }
+
+    /// Removes the key-value pair the cursor points at and returns it, or returns `None` if
+    /// the cursor points past the end.
+    ///
+    /// This shifts all following pairs down by one position, preserving their order, and the
+    /// cursor then points at the pair that followed the removed one. This is `O(n)`.
+    pub fn shift_remove_current(&mut self) -> Option<(K, V)> {
+        if self.index < self.map.storage.len() {
+            Some(self.map.storage.remove(self.index))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the occupied entry the cursor points at, or `None` if it points past the end.
+    pub fn as_entry(&mut self) -> Option<OccupiedEntry<'_, K, V>> {
+        if self.index < self.map.storage.len() {
+            Some(OccupiedEntry {
+                map: self.map,
+                index: self.index,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Returns a read-only cursor pointing at the same position.
+    pub fn as_cursor(&self) -> Cursor<'_, K, V> {
+        Cursor {
+            map: self.map,
+            index: self.index,
+        }
+    }
+}
+
+impl<'a, K: Eq, V> CursorMut<'a, K, V> {
+    /// Inserts a key-value pair before the position the cursor points at.
+    ///
+    /// The cursor keeps pointing at the same key-value pair as before.
+    ///
+    /// Returns the given key and value back if the map already contains a key that is equal to
+    /// the given key. The map is left unchanged in that case.
+    pub fn insert_before(&mut self, key: K, value: V) -> Result<(), (K, V)> {
+        if self.map.contains_key(&key) {
+            return Err((key, value));
+        }
+        self.map.storage.insert(self.index, (key, value));
+        self.index += 1;
+        Ok(())
+    }
+}
+
+/// A view into a contiguous range of a `LinearMap`'s key-value pairs.
+///
+/// Unlike `LinearMap`, a `Slice` does not require its keys to implement `Eq`, except for
+/// looking up values by key. A mutable `Slice` only allows mutating the values, not the keys.
+///
+/// Acquire through [`LinearMap::as_slice`](struct.LinearMap.html#method.as_slice) or
+/// [`LinearMap::as_mut_slice`](struct.LinearMap.html#method.as_mut_slice).
+#[repr(transparent)]
+pub struct Slice<K, V> {
+    entries: [(K, V)],
+}
+
+impl<K, V> Slice<K, V> {
+    fn from_slice(entries: &[(K, V)]) -> &Self {
+        // `Slice` is a transparent wrapper around `[(K, V)]`.
+        unsafe { &*(entries as *const [(K, V)] as *const Self) }
+    }
+
+    fn from_mut_slice(entries: &mut [(K, V)]) -> &mut Self {
+        // `Slice` is a transparent wrapper around `[(K, V)]`.
+        unsafe { &mut *(entries as *mut [(K, V)] as *mut Self) }
+    }
+
+    /// Returns the number of elements in the slice.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if the slice contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns a reference to the value in the slice whose key is equal to the given key.
+    ///
+    /// Returns `None` if the slice contains no such key.
+    ///
+    /// The given key may be any borrowed form of the slice's key type, but `Eq` on the borrowed
+    /// form *must* match that of the key type.
+    pub fn get<Q: ?Sized + Eq>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+    {
+        self.entries
+            .iter()
+            .find(|e| e.0.borrow() == key)
+            .map(|e| &e.1)
+    }
+
+    /// Returns a mutable reference to the value in the slice whose key is equal to the given
+    /// key.
+    ///
+    /// Returns `None` if the slice contains no such key.
+    ///
+    /// The given key may be any borrowed form of the slice's key type, but `Eq` on the borrowed
+    /// form *must* match that of the key type.
+    pub fn get_mut<Q: ?Sized + Eq>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+    {
+        self.entries
+            .iter_mut()
+            .find(|e| e.0.borrow() == key)
+            .map(|e| &mut e.1)
+    }
 
-/// A view into a single vacant location in a `LinearMap`.
-///
-/// See [`LinearMap::entry`](struct.LinearMap.html#method.entry) for details.
-pub struct VacantEntry<'a, K: 'a, V: 'a> {
-    map: &'a mut LinearMap<K, V>,
-    key: K,
-}
+    /// Checks if the slice contains a key that is equal to the given key.
+    ///
+    /// The given key may be any borrowed form of the slice's key type, but `Eq` on the borrowed
+    /// form *must* match that of the key type.
+    pub fn contains_key<Q: ?Sized + Eq>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        self.get(key).is_some()
+    }
 
-/// A view into a single entry in a `LinearMap`.
-///
-/// See [`LinearMap::entry`](struct.LinearMap.html#method.entry) for details.
-pub enum Entry<'a, K: 'a, V: 'a> {
-    /// An occupied entry.
-    Occupied(OccupiedEntry<'a, K, V>),
+    /// Returns the key-value pair at the given position.
+    pub fn get_index(&self, index: usize) -> Option<(&K, &V)> {
+        self.entries.get(index).map(|e| (&e.0, &e.1))
+    }
 
-    /// A vacant entry.
-    Vacant(VacantEntry<'a, K, V>),
-}
+    /// Returns the key and a mutable reference to the value at the given position.
+    pub fn get_index_mut(&mut self, index: usize) -> Option<(&K, &mut V)> {
+        self.entries.get_mut(index).map(|e| (&e.0, &mut e.1))
+    }
 
-impl<'a, K, V> Entry<'a, K, V> {
-    /// Ensures that the entry is occupied by inserting the given value if it is vacant.
+    /// Returns the sub-slice covering the given range of positions.
     ///
-    /// Returns a mutable reference to the entry's value.
-    pub fn or_insert(self, default: V) -> &'a mut V {
-        match self {
-            Occupied(entry) => entry.into_mut(),
-            Vacant(entry) => entry.insert(default),
+    /// Returns `None` if the range is out of bounds.
+    pub fn get_range<R: ops::RangeBounds<usize>>(&self, range: R) -> Option<&Self> {
+        let range = bounds_to_range(range, self.len())?;
+        self.entries.get(range).map(Slice::from_slice)
+    }
+
+    /// Returns the mutable sub-slice covering the given range of positions.
+    ///
+    /// Returns `None` if the range is out of bounds.
+    pub fn get_range_mut<R: ops::RangeBounds<usize>>(&mut self, range: R) -> Option<&mut Self> {
+        let range = bounds_to_range(range, self.len())?;
+        self.entries.get_mut(range).map(Slice::from_mut_slice)
+    }
+
+    /// Returns the first key-value pair in the slice.
+    pub fn first(&self) -> Option<(&K, &V)> {
+        self.entries.first().map(|e| (&e.0, &e.1))
+    }
+
+    /// Returns the first key and a mutable reference to its value.
+    pub fn first_mut(&mut self) -> Option<(&K, &mut V)> {
+        self.entries.first_mut().map(|e| (&e.0, &mut e.1))
+    }
+
+    /// Returns the last key-value pair in the slice.
+    pub fn last(&self) -> Option<(&K, &V)> {
+        self.entries.last().map(|e| (&e.0, &e.1))
+    }
+
+    /// Returns the last key and a mutable reference to its value.
+    pub fn last_mut(&mut self) -> Option<(&K, &mut V)> {
+        self.entries.last_mut().map(|e| (&e.0, &mut e.1))
+    }
+
+    /// Divides the slice in two at the given position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    pub fn split_at(&self, mid: usize) -> (&Self, &Self) {
+        let (a, b) = self.entries.split_at(mid);
+        (Slice::from_slice(a), Slice::from_slice(b))
+    }
+
+    /// Returns an iterator yielding references to the slice's keys and their corresponding
+    /// values.
+    ///
+    /// The iterator's item type is `(&K, &V)`.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            iter: self.entries.iter(),
         }
     }
 
-    /// Ensures that the entry is occupied by inserting the the result of the given function if it
-    /// is vacant.
+    /// Returns an iterator yielding references to the slice's keys and mutable references to
+    /// their corresponding values.
     ///
-    /// Returns a mutable reference to the entry's value.
-    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
-        match self {
-            Occupied(entry) => entry.into_mut(),
-            Vacant(entry) => entry.insert(default()),
+    /// The iterator's item type is `(&K, &mut V)`.
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut {
+            iter: self.entries.iter_mut(),
         }
     }
-}
 
-impl<'a, K, V> OccupiedEntry<'a, K, V> {
-    /// Returns a reference to the entry's value.
-    pub fn get(&self) -> &V {
-        &self.map.storage[self.index].1
+    /// Returns an iterator yielding references to the slice's keys.
+    ///
+    /// The iterator's item type is `&K`.
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys { iter: self.iter() }
     }
 
-    /// Returns a mutable reference to the entry's value.
-    pub fn get_mut(&mut self) -> &mut V {
-        &mut self.map.storage[self.index].1
+    /// Returns an iterator yielding references to the slice's values.
+    ///
+    /// The iterator's item type is `&V`.
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values { iter: self.iter() }
     }
+}
 
-    /// Returns a mutable reference to the entry's value with the same lifetime as the map.
-    pub fn into_mut(self) -> &'a mut V {
-        &mut self.map.storage[self.index].1
+fn bounds_to_range<R: ops::RangeBounds<usize>>(range: R, len: usize) -> Option<ops::Range<usize>> {
+    let start = match range.start_bound() {
+        ops::Bound::Included(&i) => i,
+        ops::Bound::Excluded(&i) => i.checked_add(1)?,
+        ops::Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        ops::Bound::Included(&i) => i.checked_add(1)?,
+        ops::Bound::Excluded(&i) => i,
+        ops::Bound::Unbounded => len,
+    };
+    if start <= end && end <= len {
+        Some(start..end)
+    } else {
+        None
     }
+}
 
-    /// Replaces the entry's value with the given one and returns the previous value.
-    pub fn insert(&mut self, value: V) -> V {
-        mem::replace(self.get_mut(), value)
+impl<K: Debug, V: Debug> Debug for Slice<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
     }
+}
 
-    /// Removes the entry from the map and returns its value.
-    pub fn remove(self) -> V {
-        self.map.storage.swap_remove(self.index).1
+macro_rules! impl_slice_index {
+    ($($range:ty),*) => {
+        $(
+            impl<K, V> ops::Index<$range> for Slice<K, V> {
+                type Output = Slice<K, V>;
+
+                fn index(&self, range: $range) -> &Slice<K, V> {
+                    Slice::from_slice(&self.entries[range])
+                }
+            }
+
+            impl<K, V> ops::IndexMut<$range> for Slice<K, V> {
+                fn index_mut(&mut self, range: $range) -> &mut Slice<K, V> {
+                    Slice::from_mut_slice(&mut self.entries[range])
+                }
+            }
+        )*
+    };
+}
+impl_slice_index!(
+    ops::Range<usize>,
+    ops::RangeFrom<usize>,
+    ops::RangeFull,
+    ops::RangeInclusive<usize>,
+    ops::RangeTo<usize>,
+    ops::RangeToInclusive<usize>
+);
+
+impl<'a, K, V> IntoIterator for &'a Slice<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
     }
 }
 
-impl<'a, K, V> VacantEntry<'a, K, V> {
-    /// Inserts the entry into the map with the given value.
-    ///
-    /// Returns a mutable reference to the entry's value with the same lifetime as the map.
-    pub fn insert(self, value: V) -> &'a mut V {
-        self.map.storage.push((self.key, value));
-        &mut self.map.storage.last_mut().unwrap().1
+impl<'a, K, V> IntoIterator for &'a mut Slice<K, V> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    fn into_iter(self) -> IterMut<'a, K, V> {
+        self.iter_mut()
     }
 }
 
@@ -519,6 +2253,13 @@ pub struct IntoIter<K, V> {
     iter: vec::IntoIter<(K, V)>,
 }
 
+impl<K, V> IntoIter<K, V> {
+    /// Returns the remaining key-value pairs as a slice.
+    pub fn as_slice(&self) -> &Slice<K, V> {
+        Slice::from_slice(self.iter.as_slice())
+    }
+}
+
 impl<K, V> Iterator for IntoIter<K, V> {
     type Item = (K, V);
 
@@ -543,6 +2284,22 @@ impl<K, V> ExactSizeIterator for IntoIter<K, V> {
     }
 }
 
+impl<K, V> FusedIterator for IntoIter<K, V> {}
+
+impl<K: Clone, V: Clone> Clone for IntoIter<K, V> {
+    fn clone(&self) -> Self {
+        IntoIter {
+            iter: self.iter.clone(),
+        }
+    }
+}
+
+impl<K: Debug, V: Debug> Debug for IntoIter<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.as_slice().iter()).finish()
+    }
+}
+
 /// A draining iterator over a `LinearMap`.
 ///
 /// See [`LinearMap::drain`](struct.LinearMap.html#method.drain) for details.
@@ -550,6 +2307,16 @@ pub struct Drain<'a, K: 'a, V: 'a> {
     iter: vec::Drain<'a, (K, V)>,
 }
 
+/// An iterator that removes and yields the key-value pairs of a `LinearMap` that match a
+/// predicate.
+///
+/// See [`LinearMap::extract_if`](struct.LinearMap.html#method.extract_if) for details.
+pub struct ExtractIf<'a, K: 'a, V: 'a, F> {
+    storage: &'a mut Vec<(K, V)>,
+    index: usize,
+    pred: F,
+}
+
 /// An iterator yielding references to a `LinearMap`'s keys and their corresponding values.
 ///
 /// See [`LinearMap::iter`](struct.LinearMap.html#method.iter) for details.
@@ -557,6 +2324,13 @@ pub struct Iter<'a, K: 'a, V: 'a> {
     iter: slice::Iter<'a, (K, V)>,
 }
 
+impl<'a, K, V> Iter<'a, K, V> {
+    /// Returns the remaining key-value pairs as a slice.
+    pub fn as_slice(&self) -> &'a Slice<K, V> {
+        Slice::from_slice(self.iter.as_slice())
+    }
+}
+
 /// An iterator yielding references to a `LinearMap`'s keys and mutable references to their
 /// corresponding values.
 ///
@@ -565,6 +2339,14 @@ pub struct IterMut<'a, K: 'a, V: 'a> {
     iter: slice::IterMut<'a, (K, V)>,
 }
 
+/// An iterator yielding references to a `LinearMap`'s keys and their corresponding values in
+/// key order.
+///
+/// See [`LinearMap::sorted_iter`](struct.LinearMap.html#method.sorted_iter) for details.
+pub struct SortedIter<'a, K: 'a, V: 'a> {
+    iter: vec::IntoIter<(&'a K, &'a V)>,
+}
+
 /// An iterator yielding references to a `LinearMap`'s keys in arbitrary order.
 ///
 /// See [`LinearMap::keys`](struct.LinearMap.html#method.keys) for details.
@@ -579,6 +2361,27 @@ pub struct Values<'a, K: 'a, V: 'a> {
     iter: Iter<'a, K, V>,
 }
 
+/// An iterator yielding mutable references to a `LinearMap`'s values in arbitrary order.
+///
+/// See [`LinearMap::values_mut`](struct.LinearMap.html#method.values_mut) for details.
+pub struct ValuesMut<'a, K: 'a, V: 'a> {
+    iter: IterMut<'a, K, V>,
+}
+
+/// A consuming iterator over the keys of a `LinearMap`.
+///
+/// See [`LinearMap::into_keys`](struct.LinearMap.html#method.into_keys) for details.
+pub struct IntoKeys<K, V> {
+    iter: vec::IntoIter<(K, V)>,
+}
+
+/// A consuming iterator over the values of a `LinearMap`.
+///
+/// See [`LinearMap::into_values`](struct.LinearMap.html#method.into_values) for details.
+pub struct IntoValues<K, V> {
+    iter: vec::IntoIter<(K, V)>,
+}
+
 macro_rules! impl_iter {
     ($typ:ty, $item:ty, $map:expr) => {
         impl<'a, K, V> Iterator for $typ {
@@ -604,6 +2407,8 @@ macro_rules! impl_iter {
                 self.iter.len()
             }
         }
+
+        impl<'a, K, V> FusedIterator for $typ {}
     };
 }
 impl_iter! {Drain<'a,K,V>,  (K,V),  |e| e }
@@ -611,6 +2416,51 @@ impl_iter! {Iter<'a,K,V>,  (&'a K, &'a V),  |e| (&e.0, &e.1) }
 impl_iter! {IterMut<'a,K,V>,  (&'a K, &'a mut V),  |e| (&e.0, &mut e.1) }
 impl_iter! {Keys<'a,K,V>,  &'a K,  |e| e.0 }
 impl_iter! {Values<'a,K,V>,  &'a V,  |e| e.1 }
+impl_iter! {ValuesMut<'a,K,V>,  &'a mut V,  |e| e.1 }
+impl_iter! {IntoKeys<K,V>,  K,  |e| e.0 }
+impl_iter! {IntoValues<K,V>,  V,  |e| e.1 }
+impl_iter! {SortedIter<'a,K,V>,  (&'a K, &'a V),  |e| e }
+
+impl<'a, K, V, F> ExtractIf<'a, K, V, F> {
+    /// Removes and returns the next pair for which `matches` returns `true`, given the predicate
+    /// and the pair.
+    ///
+    /// This lets `set::ExtractIf` drive the iterator with a predicate that ignores the values.
+    fn next_matching<M>(&mut self, mut matches: M) -> Option<(K, V)>
+    where
+        M: FnMut(&mut F, &K, &mut V) -> bool,
+    {
+        while self.index < self.storage.len() {
+            let (ref k, ref mut v) = self.storage[self.index];
+            if matches(&mut self.pred, k, v) {
+                return Some(self.storage.swap_remove(self.index));
+            }
+            self.index += 1;
+        }
+        None
+    }
+
+    fn remaining(&self) -> (usize, Option<usize>) {
+        (0, Some(self.storage.len() - self.index))
+    }
+}
+
+impl<'a, K, V, F> Iterator for ExtractIf<'a, K, V, F>
+where
+    F: FnMut(&K, &mut V) -> bool,
+{
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<(K, V)> {
+        self.next_matching(|pred, k, v| pred(k, v))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.remaining()
+    }
+}
+
+impl<'a, K, V, F> FusedIterator for ExtractIf<'a, K, V, F> where F: FnMut(&K, &mut V) -> bool {}
 
 impl<'a, K, V> Clone for Iter<'a, K, V> {
     fn clone(&self) -> Self {
@@ -620,6 +2470,14 @@ impl<'a, K, V> Clone for Iter<'a, K, V> {
     }
 }
 
+impl<'a, K, V> Clone for SortedIter<'a, K, V> {
+    fn clone(&self) -> Self {
+        SortedIter {
+            iter: self.iter.clone(),
+        }
+    }
+}
+
 impl<'a, K, V> Clone for Keys<'a, K, V> {
     fn clone(&self) -> Self {
         Keys {
@@ -636,6 +2494,89 @@ impl<'a, K, V> Clone for Values<'a, K, V> {
     }
 }
 
+impl<K: Clone, V: Clone> Clone for IntoKeys<K, V> {
+    fn clone(&self) -> Self {
+        IntoKeys {
+            iter: self.iter.clone(),
+        }
+    }
+}
+
+impl<K: Clone, V: Clone> Clone for IntoValues<K, V> {
+    fn clone(&self) -> Self {
+        IntoValues {
+            iter: self.iter.clone(),
+        }
+    }
+}
+
+impl<'a, K: Debug, V: Debug> Debug for Iter<'a, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<'a, K: Debug, V: Debug> Debug for IterMut<'a, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let iter = self.iter.as_slice().iter().map(|e| (&e.0, &e.1));
+        f.debug_list().entries(iter).finish()
+    }
+}
+
+impl<'a, K: Debug, V: Debug> Debug for SortedIter<'a, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<'a, K: Debug, V> Debug for Keys<'a, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<'a, K, V: Debug> Debug for Values<'a, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<'a, K, V: Debug> Debug for ValuesMut<'a, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list()
+            .entries(self.iter.iter.as_slice().iter().map(|e| &e.1))
+            .finish()
+    }
+}
+
+impl<K: Debug, V> Debug for IntoKeys<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list()
+            .entries(self.iter.as_slice().iter().map(|e| &e.0))
+            .finish()
+    }
+}
+
+impl<K, V: Debug> Debug for IntoValues<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list()
+            .entries(self.iter.as_slice().iter().map(|e| &e.1))
+            .finish()
+    }
+}
+
+impl<'a, K: Debug, V: Debug> Debug for Drain<'a, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter.as_slice()).finish()
+    }
+}
+
+impl<'a, K, V, F> Debug for ExtractIf<'a, K, V, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ExtractIf").finish_non_exhaustive()
+    }
+}
+
 impl<K: Eq, V> IntoIterator for LinearMap<K, V> {
     type Item = (K, V);
     type IntoIter = IntoIter<K, V>;
@@ -686,4 +2627,20 @@ fn assert_covariance() {
     fn e<'i, 'a, K, V>(x: Values<'i, &'static K, &'static V>) -> Values<'i, &'a K, &'a V> {
         x
     }
+
+    fn f<'a, K, V>(x: IntoKeys<&'static K, &'static V>) -> IntoKeys<&'a K, &'a V> {
+        x
+    }
+
+    fn g<'a, K, V>(x: IntoValues<&'static K, &'static V>) -> IntoValues<&'a K, &'a V> {
+        x
+    }
+
+    fn h<'i, K, V>(x: ValuesMut<'static, K, V>) -> ValuesMut<'i, K, V> {
+        x
+    }
+
+    fn i<'i, 'a, K, V>(x: KeySet<'i, &'static K, &'static V>) -> KeySet<'i, &'a K, &'a V> {
+        x
+    }
 }
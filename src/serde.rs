@@ -9,11 +9,15 @@
 
 extern crate serde;
 
+use super::bag::LinearBag;
+use super::diff::{Change, Patch};
 use super::set::LinearSet;
 use super::LinearMap;
 
-use self::serde::de::{Error, MapAccess, SeqAccess, Visitor};
-use self::serde::ser::{SerializeMap, SerializeSeq};
+use self::serde::de::{
+    EnumAccess, Error, MapAccess, SeqAccess, Unexpected, VariantAccess, Visitor,
+};
+use self::serde::ser::{SerializeMap, SerializeSeq, SerializeTupleVariant};
 use self::serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use std::fmt;
@@ -29,9 +33,9 @@ where
     where
         S: Serializer,
     {
-        let mut state = try!(serializer.serialize_map(Some(self.len())));
+        let mut state = serializer.serialize_map(Some(self.len()))?;
         for (k, v) in self {
-            try!(state.serialize_entry(k, v));
+            state.serialize_entry(k, v)?;
         }
         state.end()
     }
@@ -51,6 +55,12 @@ impl<K, V> LinearMapVisitor<K, V> {
     }
 }
 
+impl<K, V> Default for LinearMapVisitor<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<'de, K, V> Visitor<'de> for LinearMapVisitor<K, V>
 where
     K: Deserialize<'de> + Eq,
@@ -77,7 +87,7 @@ where
     {
         let mut values = LinearMap::with_capacity(visitor.size_hint().unwrap_or(0));
 
-        while let Some((key, value)) = try!(visitor.next_entry()) {
+        while let Some((key, value)) = visitor.next_entry()? {
             values.insert(key, value);
         }
 
@@ -107,9 +117,9 @@ where
     where
         S: Serializer,
     {
-        let mut state = try!(serializer.serialize_seq(Some(self.len())));
+        let mut state = serializer.serialize_seq(Some(self.len()))?;
         for k in self {
-            try!(state.serialize_element(k));
+            state.serialize_element(k)?;
         }
         state.end()
     }
@@ -129,6 +139,12 @@ impl<K> LinearSetVisitor<K> {
     }
 }
 
+impl<K> Default for LinearSetVisitor<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<'de, K> Visitor<'de> for LinearSetVisitor<K>
 where
     K: Deserialize<'de> + Eq,
@@ -154,7 +170,7 @@ where
     {
         let mut values = LinearSet::with_capacity(visitor.size_hint().unwrap_or(0));
 
-        while let Some(key) = try!(visitor.next_element()) {
+        while let Some(key) = visitor.next_element()? {
             values.insert(key);
         }
 
@@ -173,3 +189,303 @@ where
         deserializer.deserialize_seq(LinearSetVisitor::new())
     }
 }
+
+impl<T> Serialize for LinearBag<T>
+where
+    T: Serialize + Eq,
+{
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_map().serialize(serializer)
+    }
+}
+
+struct LinearBagVisitor<T> {
+    marker: PhantomData<LinearBag<T>>,
+}
+
+impl<'de, T> Visitor<'de> for LinearBagVisitor<T>
+where
+    T: Deserialize<'de> + Eq,
+{
+    type Value = LinearBag<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a LinearBag")
+    }
+
+    #[inline]
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(LinearBag::new())
+    }
+
+    #[inline]
+    fn visit_map<Visitor>(self, mut visitor: Visitor) -> Result<Self::Value, Visitor::Error>
+    where
+        Visitor: MapAccess<'de>,
+    {
+        let mut values = LinearBag::with_capacity(visitor.size_hint().unwrap_or(0));
+
+        while let Some((value, n)) = visitor.next_entry()? {
+            values.add_n(value, n);
+        }
+
+        Ok(values)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for LinearBag<T>
+where
+    T: Deserialize<'de> + Eq,
+{
+    fn deserialize<D>(deserializer: D) -> Result<LinearBag<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let visitor = LinearBagVisitor {
+            marker: PhantomData,
+        };
+        deserializer.deserialize_map(visitor)
+    }
+}
+
+const CHANGE_VARIANTS: &[&str] = &["Added", "Removed", "Modified"];
+
+impl<K, V> Serialize for Change<K, V>
+where
+    K: Serialize,
+    V: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self {
+            Change::Added(ref k, ref v) => {
+                let mut state = serializer.serialize_tuple_variant("Change", 0, "Added", 2)?;
+                state.serialize_field(k)?;
+                state.serialize_field(v)?;
+                state.end()
+            }
+            Change::Removed(ref k, ref v) => {
+                let mut state = serializer.serialize_tuple_variant("Change", 1, "Removed", 2)?;
+                state.serialize_field(k)?;
+                state.serialize_field(v)?;
+                state.end()
+            }
+            Change::Modified(ref k, ref old, ref new) => {
+                let mut state = serializer.serialize_tuple_variant("Change", 2, "Modified", 3)?;
+                state.serialize_field(k)?;
+                state.serialize_field(old)?;
+                state.serialize_field(new)?;
+                state.end()
+            }
+        }
+    }
+}
+
+enum ChangeTag {
+    Added,
+    Removed,
+    Modified,
+}
+
+struct ChangeTagVisitor;
+
+impl<'de> Visitor<'de> for ChangeTagVisitor {
+    type Value = ChangeTag;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a Change variant")
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<ChangeTag, E>
+    where
+        E: Error,
+    {
+        match value {
+            0 => Ok(ChangeTag::Added),
+            1 => Ok(ChangeTag::Removed),
+            2 => Ok(ChangeTag::Modified),
+            _ => Err(E::invalid_value(Unexpected::Unsigned(value), &self)),
+        }
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<ChangeTag, E>
+    where
+        E: Error,
+    {
+        match value {
+            "Added" => Ok(ChangeTag::Added),
+            "Removed" => Ok(ChangeTag::Removed),
+            "Modified" => Ok(ChangeTag::Modified),
+            _ => Err(E::unknown_variant(value, CHANGE_VARIANTS)),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ChangeTag {
+    fn deserialize<D>(deserializer: D) -> Result<ChangeTag, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_identifier(ChangeTagVisitor)
+    }
+}
+
+struct ChangeFieldsVisitor<K, V> {
+    tag: ChangeTag,
+    marker: PhantomData<Change<K, V>>,
+}
+
+impl<'de, K, V> Visitor<'de> for ChangeFieldsVisitor<K, V>
+where
+    K: Deserialize<'de>,
+    V: Deserialize<'de>,
+{
+    type Value = Change<K, V>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a Change")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let k = seq
+            .next_element()?
+            .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+        let v = seq
+            .next_element()?
+            .ok_or_else(|| A::Error::invalid_length(1, &self))?;
+        Ok(match self.tag {
+            ChangeTag::Added => Change::Added(k, v),
+            ChangeTag::Removed => Change::Removed(k, v),
+            ChangeTag::Modified => {
+                let new = seq
+                    .next_element()?
+                    .ok_or_else(|| A::Error::invalid_length(2, &self))?;
+                Change::Modified(k, v, new)
+            }
+        })
+    }
+}
+
+struct ChangeVisitor<K, V> {
+    marker: PhantomData<Change<K, V>>,
+}
+
+impl<'de, K, V> Visitor<'de> for ChangeVisitor<K, V>
+where
+    K: Deserialize<'de>,
+    V: Deserialize<'de>,
+{
+    type Value = Change<K, V>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a Change")
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let (tag, variant) = data.variant()?;
+        let len = match tag {
+            ChangeTag::Added | ChangeTag::Removed => 2,
+            ChangeTag::Modified => 3,
+        };
+        let visitor = ChangeFieldsVisitor {
+            tag,
+            marker: PhantomData,
+        };
+        variant.tuple_variant(len, visitor)
+    }
+}
+
+impl<'de, K, V> Deserialize<'de> for Change<K, V>
+where
+    K: Deserialize<'de>,
+    V: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Change<K, V>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let visitor = ChangeVisitor {
+            marker: PhantomData,
+        };
+        deserializer.deserialize_enum("Change", CHANGE_VARIANTS, visitor)
+    }
+}
+
+impl<K, V> Serialize for Patch<K, V>
+where
+    K: Serialize,
+    V: Serialize,
+{
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.len()))?;
+        for change in self {
+            state.serialize_element(change)?;
+        }
+        state.end()
+    }
+}
+
+struct PatchVisitor<K, V> {
+    marker: PhantomData<Patch<K, V>>,
+}
+
+impl<'de, K, V> Visitor<'de> for PatchVisitor<K, V>
+where
+    K: Deserialize<'de>,
+    V: Deserialize<'de>,
+{
+    type Value = Patch<K, V>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a Patch")
+    }
+
+    #[inline]
+    fn visit_seq<Visitor>(self, mut visitor: Visitor) -> Result<Self::Value, Visitor::Error>
+    where
+        Visitor: SeqAccess<'de>,
+    {
+        let mut changes = Vec::with_capacity(visitor.size_hint().unwrap_or(0));
+
+        while let Some(change) = visitor.next_element()? {
+            changes.push(change);
+        }
+
+        Ok(Patch::from(changes))
+    }
+}
+
+impl<'de, K, V> Deserialize<'de> for Patch<K, V>
+where
+    K: Deserialize<'de>,
+    V: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Patch<K, V>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let visitor = PatchVisitor {
+            marker: PhantomData,
+        };
+        deserializer.deserialize_seq(visitor)
+    }
+}
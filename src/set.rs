@@ -3,11 +3,18 @@
 //! See the [`LinearSet`](struct.LinearSet.html) type for details.
 
 use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::collections::{btree_set, hash_set, BTreeSet, HashSet};
 use std::fmt;
-use std::iter::{Chain, FromIterator};
-use std::ops::{BitAnd, BitOr, BitXor, Sub};
+use std::hash::{BuildHasher, Hash, Hasher};
+use std::iter::{Chain, FromIterator, FusedIterator};
+use std::mem;
+use std::ops::{
+    BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, RangeBounds, Sub, SubAssign,
+};
+use std::slice;
 
-use super::{Keys, LinearMap};
+use super::{DedupPolicy, DuplicateKeyError, KeySet, Keys, LinearMap};
 
 /// An implementation of a set using the underlying representation of a
 /// LinearMap where the value is ().
@@ -80,7 +87,6 @@ impl<T: Eq> LinearSet<T> {
     /// let mut set: LinearSet<i32> = LinearSet::new();
     /// ```
     #[inline]
-
     pub fn new() -> LinearSet<T> {
         LinearSet {
             map: LinearMap::new(),
@@ -102,6 +108,75 @@ impl<T: Eq> LinearSet<T> {
             map: LinearMap::with_capacity(capacity),
         }
     }
+
+    /// Creates a LinearSet from a vector, checking that its elements are unique.
+    ///
+    /// Returns an error naming the positions of the first two equal elements otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    ///
+    /// assert!(LinearSet::try_from_vec(vec![1, 2, 3]).is_ok());
+    ///
+    /// let err = LinearSet::try_from_vec(vec![1, 2, 1]).unwrap_err();
+    /// assert_eq!((err.first(), err.second()), (0, 2));
+    /// ```
+    pub fn try_from_vec(values: Vec<T>) -> Result<LinearSet<T>, DuplicateKeyError> {
+        let storage = values.into_iter().map(|v| (v, ())).collect();
+        LinearMap::try_from_vec(storage).map(|map| LinearSet { map })
+    }
+
+    /// Creates a LinearSet from a vector, dropping duplicate elements according to the given
+    /// policy.
+    ///
+    /// The remaining elements keep their relative order. An element that wins over an earlier
+    /// one takes its position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    /// use linear_map::DedupPolicy;
+    ///
+    /// let set = LinearSet::from_vec_dedup(vec![3, 1, 3, 2, 1], DedupPolicy::FirstWins);
+    /// assert_eq!(Vec::from(set), [3, 1, 2]);
+    /// ```
+    pub fn from_vec_dedup(values: Vec<T>, policy: DedupPolicy) -> LinearSet<T> {
+        let storage = values.into_iter().map(|v| (v, ())).collect();
+        LinearSet {
+            map: LinearMap::from_vec_dedup(storage, policy),
+        }
+    }
+
+    /// Creates a LinearSet from a vector without checking that its elements are unique.
+    ///
+    /// It is a logic error for the vector to contain two equal elements. The behavior resulting
+    /// from such a logic error is not specified, but will not result in undefined behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    ///
+    /// let set = LinearSet::from_vec_unchecked(vec![1, 2, 3]);
+    /// assert_eq!(set.len(), 3);
+    /// ```
+    pub fn from_vec_unchecked(values: Vec<T>) -> LinearSet<T> {
+        let storage = values.into_iter().map(|v| (v, ())).collect();
+        LinearSet {
+            map: LinearMap::from_vec_unchecked(storage),
+        }
+    }
+
+    /// Creates a LinearSet from the elements of another set, which are already unique and so are
+    /// not compared. Used by the conversions from other crates' sets.
+    pub(crate) fn from_unique<I: IntoIterator<Item = T>>(values: I) -> LinearSet<T> {
+        LinearSet {
+            map: LinearMap::from_unique(values.into_iter().map(|v| (v, ()))),
+        }
+    }
 }
 
 impl<T> LinearSet<T>
@@ -118,7 +193,6 @@ where
     /// assert!(set.capacity() >= 100);
     /// ```
     #[inline]
-
     pub fn capacity(&self) -> usize {
         self.map.capacity()
     }
@@ -138,7 +212,6 @@ where
     /// let mut set: LinearSet<i32> = LinearSet::new();
     /// set.reserve(10);
     /// ```
-
     pub fn reserve(&mut self, additional: usize) {
         self.map.reserve(additional)
     }
@@ -159,7 +232,6 @@ where
     /// set.shrink_to_fit();
     /// assert!(set.capacity() >= 2);
     /// ```
-
     pub fn shrink_to_fit(&mut self) {
         self.map.shrink_to_fit()
     }
@@ -180,8 +252,7 @@ where
     ///     println!("{}", x);
     /// }
     /// ```
-
-    pub fn iter(&self) -> Iter<T> {
+    pub fn iter(&self) -> Iter<'_, T> {
         Iter {
             iter: self.map.keys(),
         }
@@ -202,15 +273,30 @@ where
     /// }
     ///
     /// let diff: LinearSet<_> = a.difference(&b).cloned().collect();
-    /// assert_eq!(diff, [1].iter().cloned().collect());
+    /// assert_eq!(diff, [1].iter().cloned().collect::<LinearSet<_>>());
     ///
     /// // Note that difference is not symmetric,
     /// // and `b - a` means something else:
     /// let diff: LinearSet<_> = b.difference(&a).cloned().collect();
-    /// assert_eq!(diff, [4].iter().cloned().collect());
+    /// assert_eq!(diff, [4].iter().cloned().collect::<LinearSet<_>>());
     /// ```
-
-    pub fn difference<'a>(&'a self, other: &'a LinearSet<T>) -> Difference<'a, T> {
+    ///
+    /// `other` may be any [`SetLike`](trait.SetLike.html) collection:
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    /// use std::collections::HashSet;
+    ///
+    /// let a: LinearSet<_> = [1, 2, 3].iter().cloned().collect();
+    /// let b: HashSet<_> = [2, 3, 4].iter().cloned().collect();
+    ///
+    /// let diff: Vec<_> = a.difference(&b).cloned().collect();
+    /// assert_eq!(diff, [1]);
+    /// ```
+    pub fn difference<'a, S>(&'a self, other: &'a S) -> Difference<'a, T, S>
+    where
+        S: SetLike<T> + ?Sized,
+    {
         Difference {
             iter: self.iter(),
             other,
@@ -235,9 +321,8 @@ where
     /// let diff2: LinearSet<_> = b.symmetric_difference(&a).cloned().collect();
     ///
     /// assert_eq!(diff1, diff2);
-    /// assert_eq!(diff1, [1, 4].iter().cloned().collect());
+    /// assert_eq!(diff1, [1, 4].iter().cloned().collect::<LinearSet<_>>());
     /// ```
-
     pub fn symmetric_difference<'a>(
         &'a self,
         other: &'a LinearSet<T>,
@@ -262,10 +347,25 @@ where
     /// }
     ///
     /// let intersection: LinearSet<_> = a.intersection(&b).cloned().collect();
-    /// assert_eq!(intersection, [2, 3].iter().cloned().collect());
+    /// assert_eq!(intersection, [2, 3].iter().cloned().collect::<LinearSet<_>>());
     /// ```
-
-    pub fn intersection<'a>(&'a self, other: &'a LinearSet<T>) -> Intersection<'a, T> {
+    ///
+    /// `other` may be any [`SetLike`](trait.SetLike.html) collection:
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    /// use std::collections::BTreeSet;
+    ///
+    /// let a: LinearSet<_> = [1, 2, 3].iter().cloned().collect();
+    /// let b: BTreeSet<_> = [2, 3, 4].iter().cloned().collect();
+    ///
+    /// let intersection: Vec<_> = a.intersection(&b).cloned().collect();
+    /// assert_eq!(intersection, [2, 3]);
+    /// ```
+    pub fn intersection<'a, S>(&'a self, other: &'a S) -> Intersection<'a, T, S>
+    where
+        S: SetLike<T> + ?Sized,
+    {
         Intersection {
             iter: self.iter(),
             other,
@@ -287,15 +387,116 @@ where
     /// }
     ///
     /// let union: LinearSet<_> = a.union(&b).cloned().collect();
-    /// assert_eq!(union, [1, 2, 3, 4].iter().cloned().collect());
+    /// assert_eq!(union, [1, 2, 3, 4].iter().cloned().collect::<LinearSet<_>>());
     /// ```
-
     pub fn union<'a>(&'a self, other: &'a LinearSet<T>) -> Union<'a, T> {
         Union {
             iter: self.iter().chain(other.difference(self)),
         }
     }
 
+    /// Adds the values of `other` that are not in the set, turning the set into the union
+    /// of the two without allocating a new set.
+    ///
+    /// This is equivalent to `self |= other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    /// let mut a: LinearSet<_> = [1, 2, 3].iter().cloned().collect();
+    /// let b: LinearSet<_> = [2, 3, 4].iter().cloned().collect();
+    ///
+    /// a.union_with(&b);
+    /// assert_eq!(a, [1, 2, 3, 4].iter().cloned().collect::<LinearSet<_>>());
+    /// ```
+    pub fn union_with<S>(&mut self, other: &S)
+    where
+        T: Clone,
+        S: SetLike<T> + ?Sized,
+    {
+        for value in other.iter() {
+            if !self.contains(value) {
+                self.map.storage.push((value.clone(), ()));
+            }
+        }
+    }
+
+    /// Removes the values that are not in `other`, turning the set into the intersection
+    /// of the two without allocating a new set.
+    ///
+    /// This is equivalent to `self &= other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    /// let mut a: LinearSet<_> = [1, 2, 3].iter().cloned().collect();
+    /// let b: LinearSet<_> = [2, 3, 4].iter().cloned().collect();
+    ///
+    /// a.intersect_with(&b);
+    /// assert_eq!(a, [2, 3].iter().cloned().collect::<LinearSet<_>>());
+    /// ```
+    pub fn intersect_with<S>(&mut self, other: &S)
+    where
+        S: SetLike<T> + ?Sized,
+    {
+        self.retain(|value| other.contains(value));
+    }
+
+    /// Removes the values that are in `other`, turning the set into the difference of the
+    /// two without allocating a new set.
+    ///
+    /// This is equivalent to `self -= other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    /// let mut a: LinearSet<_> = [1, 2, 3].iter().cloned().collect();
+    /// let b: LinearSet<_> = [2, 3, 4].iter().cloned().collect();
+    ///
+    /// a.difference_with(&b);
+    /// assert_eq!(a, [1].iter().cloned().collect::<LinearSet<_>>());
+    /// ```
+    pub fn difference_with<S>(&mut self, other: &S)
+    where
+        S: SetLike<T> + ?Sized,
+    {
+        self.retain(|value| !other.contains(value));
+    }
+
+    /// Removes the values that are in `other` and adds those of `other` that were not in the
+    /// set, turning the set into the symmetric difference of the two.
+    ///
+    /// This is equivalent to `self ^= other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    /// let mut a: LinearSet<_> = [1, 2, 3].iter().cloned().collect();
+    /// let b: LinearSet<_> = [2, 3, 4].iter().cloned().collect();
+    ///
+    /// a.symmetric_difference_with(&b);
+    /// assert_eq!(a, [1, 4].iter().cloned().collect::<LinearSet<_>>());
+    /// ```
+    pub fn symmetric_difference_with<S>(&mut self, other: &S)
+    where
+        T: Clone,
+        S: SetLike<T> + ?Sized,
+    {
+        let added: Vec<_> = other
+            .iter()
+            .filter(|value| !self.contains(*value))
+            .cloned()
+            .collect();
+        self.difference_with(other);
+        self.map
+            .storage
+            .extend(added.into_iter().map(|value| (value, ())));
+    }
+
     /// Returns the number of elements in the set.
     ///
     /// # Examples
@@ -308,7 +509,6 @@ where
     /// v.insert(1);
     /// assert_eq!(v.len(), 1);
     /// ```
-
     pub fn len(&self) -> usize {
         self.map.len()
     }
@@ -325,16 +525,121 @@ where
     /// v.insert(1);
     /// assert!(!v.is_empty());
     /// ```
-
     pub fn is_empty(&self) -> bool {
         self.map.is_empty()
     }
 
-    /// Clears the set, returning all elements in an iterator.
+    /// Removes the elements in the given range of positions from the set and returns them in
+    /// an iterator. Use `..` to remove all of them.
+    ///
+    /// The elements following the range keep their order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is greater than its end or if its end is greater than
+    /// the length of the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    ///
+    /// let mut set: LinearSet<_> = (1..6).collect();
+    /// let drained: Vec<_> = set.drain(1..3).collect();
+    /// assert_eq!(drained, [2, 3]);
+    /// assert_eq!(Vec::from(set), [1, 4, 5]);
+    /// ```
     #[inline]
-    pub fn drain(&mut self) -> Drain<T> {
+    pub fn drain<R>(&mut self, range: R) -> Drain<'_, T>
+    where
+        R: RangeBounds<usize>,
+    {
         Drain {
-            iter: self.map.drain(),
+            iter: self.map.drain(range),
+        }
+    }
+
+    /// Splits the set in two at the given position.
+    ///
+    /// Returns a newly allocated set containing the elements at positions `at..`, while `self`
+    /// keeps those at positions `..at` and its capacity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    ///
+    /// let mut set: LinearSet<_> = (1..6).collect();
+    /// let tail = set.split_off(3);
+    /// assert_eq!(Vec::from(set), [1, 2, 3]);
+    /// assert_eq!(Vec::from(tail), [4, 5]);
+    /// ```
+    pub fn split_off(&mut self, at: usize) -> LinearSet<T> {
+        LinearSet {
+            map: self.map.split_off(at),
+        }
+    }
+
+    /// Shortens the set, keeping the first `len` elements and dropping the rest.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    ///
+    /// let mut set: LinearSet<_> = (1..6).collect();
+    /// set.truncate(2);
+    /// assert_eq!(Vec::from(set), [1, 2]);
+    /// ```
+    pub fn truncate(&mut self, len: usize) {
+        self.map.truncate(len)
+    }
+
+    /// Removes the last element from the set and returns it, or `None` if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    ///
+    /// let mut set: LinearSet<_> = (1..3).collect();
+    /// assert_eq!(set.pop(), Some(2));
+    /// assert_eq!(set.pop(), Some(1));
+    /// assert_eq!(set.pop(), None);
+    /// ```
+    pub fn pop(&mut self) -> Option<T> {
+        self.map.pop().map(|(k, _)| k)
+    }
+
+    /// Moves all elements from `other` into `self`, leaving `other` empty but keeping its
+    /// capacity.
+    ///
+    /// If an element from `other` is already present in `self`, it replaces the element in
+    /// `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    ///
+    /// let mut a: LinearSet<_> = (1..4).collect();
+    /// let mut b: LinearSet<_> = (3..6).collect();
+    /// a.append(&mut b);
+    /// assert_eq!(Vec::from(a), [1, 2, 3, 4, 5]);
+    /// assert!(b.is_empty());
+    /// ```
+    pub fn append(&mut self, other: &mut LinearSet<T>) {
+        let storage = &mut self.map.storage;
+        storage.reserve(other.len());
+        for (value, ()) in other.map.storage.drain(..) {
+            match storage.iter().position(|e| e.0 == value) {
+                Some(index) => storage[index].0 = value,
+                None => storage.push((value, ())),
+            }
         }
     }
 
@@ -358,6 +663,15 @@ where
     ///
     /// In other words, remove all elements `e` such that `f(&e)` returns `false`.
     ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    ///
+    /// let mut set: LinearSet<_> = (1..7).collect();
+    /// set.retain(|&x| x % 2 == 0);
+    /// assert_eq!(set, [2, 4, 6].iter().cloned().collect::<LinearSet<_>>());
+    /// ```
     pub fn retain<F>(&mut self, mut f: F)
     where
         F: FnMut(&T) -> bool,
@@ -365,119 +679,335 @@ where
         self.map.retain(|k, _| f(k));
     }
 
-    /// Returns `true` if the set contains a value.
+    /// Creates an iterator that removes the elements for which the closure returns `true` and
+    /// yields them.
     ///
-    /// The value may be any borrowed form of the set's value type, but
-    /// `Eq` on the borrowed form *must* match those for
-    /// the value type.
+    /// Elements for which the closure returns `false` remain in the set, and so do any elements
+    /// the iterator has not visited yet when it is dropped.
     ///
     /// # Examples
     ///
     /// ```
     /// use linear_map::set::LinearSet;;
     ///
-    /// let set: LinearSet<_> = [1, 2, 3].iter().cloned().collect();
-    /// assert_eq!(set.contains(&1), true);
-    /// assert_eq!(set.contains(&4), false);
+    /// let mut set: LinearSet<_> = (1..7).collect();
+    /// let mut odds: Vec<_> = set.extract_if(|&x| x % 2 == 1).collect();
+    /// odds.sort();
+    ///
+    /// assert_eq!(odds, [1, 3, 5]);
+    /// assert_eq!(set, [2, 4, 6].iter().cloned().collect::<LinearSet<_>>());
     /// ```
-    pub fn contains<Q: ?Sized>(&self, value: &Q) -> bool
+    pub fn extract_if<F>(&mut self, pred: F) -> ExtractIf<'_, T, F>
     where
-        T: Borrow<Q>,
-        Q: Eq,
+        F: FnMut(&T) -> bool,
     {
-        self.map.contains_key(value)
+        ExtractIf {
+            iter: super::ExtractIf {
+                storage: &mut self.map.storage,
+                index: 0,
+                pred,
+            },
+        }
     }
 
-    /// Returns `true` if the set has no elements in common with `other`.
-    /// This is equivalent to checking for an empty intersection.
+    /// Splits the set in two, according to the given closure.
+    ///
+    /// The first set contains the elements for which the closure returns `true`, the second set
+    /// those for which it returns `false`. The second set reuses the allocation of `self`.
     ///
     /// # Examples
     ///
     /// ```
     /// use linear_map::set::LinearSet;;
     ///
-    /// let a: LinearSet<_> = [1, 2, 3].iter().cloned().collect();
-    /// let mut b = LinearSet::new();
+    /// let set: LinearSet<_> = (1..7).collect();
+    /// let (evens, odds) = set.partition(|&x| x % 2 == 0);
     ///
-    /// assert_eq!(a.is_disjoint(&b), true);
-    /// b.insert(4);
-    /// assert_eq!(a.is_disjoint(&b), true);
-    /// b.insert(1);
-    /// assert_eq!(a.is_disjoint(&b), false);
+    /// assert_eq!(evens, [2, 4, 6].iter().cloned().collect::<LinearSet<_>>());
+    /// assert_eq!(odds, [1, 3, 5].iter().cloned().collect::<LinearSet<_>>());
     /// ```
-
-    pub fn is_disjoint(&self, other: &LinearSet<T>) -> bool {
-        self.iter().all(|v| !other.contains(v))
+    pub fn partition<F>(self, mut pred: F) -> (LinearSet<T>, LinearSet<T>)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let (matched, rest) = self.map.partition(|k, _| pred(k));
+        (LinearSet { map: matched }, LinearSet { map: rest })
     }
 
-    /// Returns `true` if the set is a subset of another.
+    /// Sorts the set's elements.
     ///
     /// # Examples
     ///
     /// ```
     /// use linear_map::set::LinearSet;;
     ///
-    /// let sup: LinearSet<_> = [1, 2, 3].iter().cloned().collect();
-    /// let mut set = LinearSet::new();
-    ///
-    /// assert_eq!(set.is_subset(&sup), true);
-    /// set.insert(2);
-    /// assert_eq!(set.is_subset(&sup), true);
-    /// set.insert(4);
-    /// assert_eq!(set.is_subset(&sup), false);
+    /// let mut set: LinearSet<_> = [3, 1, 2].iter().cloned().collect();
+    /// set.sort();
+    /// assert_eq!(Vec::from(set), [1, 2, 3]);
     /// ```
-
-    pub fn is_subset(&self, other: &LinearSet<T>) -> bool {
-        self.iter().all(|v| other.contains(v))
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.map.sort_keys()
     }
 
-    /// Returns `true` if the set is a superset of another.
+    /// Sorts the set's elements with the given comparison function. The sort is stable.
     ///
     /// # Examples
     ///
     /// ```
     /// use linear_map::set::LinearSet;;
     ///
-    /// let sub: LinearSet<_> = [1, 2].iter().cloned().collect();
-    /// let mut set = LinearSet::new();
+    /// let mut set: LinearSet<_> = [3, 1, 2].iter().cloned().collect();
+    /// set.sort_by(|a, b| b.cmp(a));
+    /// assert_eq!(Vec::from(set), [3, 2, 1]);
+    /// ```
+    pub fn sort_by<F>(&mut self, mut cmp: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.map.sort_by(|a, _, b, _| cmp(a, b))
+    }
+
+    /// Sorts the set's elements with the given key extraction function. The sort is unstable.
     ///
-    /// assert_eq!(set.is_superset(&sub), false);
+    /// # Examples
     ///
-    /// set.insert(0);
-    /// set.insert(1);
-    /// assert_eq!(set.is_superset(&sub), false);
+    /// ```
+    /// use linear_map::set::LinearSet;;
     ///
-    /// set.insert(2);
-    /// assert_eq!(set.is_superset(&sub), true);
+    /// let mut set: LinearSet<i32> = [-3, 1, -2].iter().cloned().collect();
+    /// set.sort_unstable_by_key(|x| x.abs());
+    /// assert_eq!(Vec::from(set), [1, -2, -3]);
     /// ```
-    #[inline]
-
-    pub fn is_superset(&self, other: &LinearSet<T>) -> bool {
-        other.is_subset(self)
+    pub fn sort_unstable_by_key<K, F>(&mut self, mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.map.sort_unstable_by_key(|k, _| f(k))
     }
 
-    /// Adds a value to the set.
-    ///
-    /// If the set did not have a value present, `true` is returned.
-    ///
-    /// If the set did have this key present, `false` is returned.
+    /// Sorts the set's elements with the given key extraction function, calling it only once
+    /// per element. The sort is stable.
     ///
     /// # Examples
     ///
     /// ```
     /// use linear_map::set::LinearSet;;
     ///
-    /// let mut set = LinearSet::new();
-    ///
-    /// assert_eq!(set.insert(2), true);
-    /// assert_eq!(set.insert(2), false);
-    /// assert_eq!(set.len(), 1);
+    /// let mut set: LinearSet<_> = ["ccc", "a", "bb"].iter().cloned().collect();
+    /// set.sort_by_cached_key(|s| s.len());
+    /// assert_eq!(Vec::from(set), ["a", "bb", "ccc"]);
+    /// ```
+    pub fn sort_by_cached_key<K, F>(&mut self, mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.map.sort_by_cached_key(|k, _| f(k))
+    }
+
+    /// Reverses the order of the set's elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    ///
+    /// let mut set: LinearSet<_> = [1, 2, 3].iter().cloned().collect();
+    /// set.reverse();
+    /// assert_eq!(Vec::from(set), [3, 2, 1]);
+    /// ```
+    pub fn reverse(&mut self) {
+        self.map.reverse()
+    }
+
+    /// An iterator visiting all elements in ascending order, without reordering the set
+    /// itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    ///
+    /// let set: LinearSet<_> = [3, 1, 2].iter().cloned().collect();
+    /// let sorted: Vec<_> = set.sorted_iter().cloned().collect();
+    /// assert_eq!(sorted, [1, 2, 3]);
+    /// ```
+    pub fn sorted_iter(&self) -> SortedIter<'_, T>
+    where
+        T: Ord,
+    {
+        SortedIter {
+            iter: self.map.sorted_iter(),
+        }
+    }
+
+    /// Returns `true` if the set contains a value.
+    ///
+    /// The value may be any borrowed form of the set's value type, but
+    /// `Eq` on the borrowed form *must* match those for
+    /// the value type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    ///
+    /// let set: LinearSet<_> = [1, 2, 3].iter().cloned().collect();
+    /// assert_eq!(set.contains(&1), true);
+    /// assert_eq!(set.contains(&4), false);
     /// ```
+    pub fn contains<Q>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: ?Sized + Eq,
+    {
+        self.map.contains_key(value)
+    }
 
+    /// Returns `true` if the set has no elements in common with `other`.
+    /// This is equivalent to checking for an empty intersection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    ///
+    /// let a: LinearSet<_> = [1, 2, 3].iter().cloned().collect();
+    /// let mut b = LinearSet::new();
+    ///
+    /// assert_eq!(a.is_disjoint(&b), true);
+    /// b.insert(4);
+    /// assert_eq!(a.is_disjoint(&b), true);
+    /// b.insert(1);
+    /// assert_eq!(a.is_disjoint(&b), false);
+    /// ```
+    ///
+    /// `other` may be any [`SetLike`](trait.SetLike.html) collection:
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    /// use std::collections::HashSet;
+    ///
+    /// let a: LinearSet<_> = [1, 2, 3].iter().cloned().collect();
+    /// let b: HashSet<_> = [4, 5].iter().cloned().collect();
+    ///
+    /// assert!(a.is_disjoint(&b));
+    /// ```
+    pub fn is_disjoint<S>(&self, other: &S) -> bool
+    where
+        S: SetLike<T> + ?Sized,
+    {
+        self.iter().all(|v| !other.contains(v))
+    }
+
+    /// Returns `true` if the set is a subset of another.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    ///
+    /// let sup: LinearSet<_> = [1, 2, 3].iter().cloned().collect();
+    /// let mut set = LinearSet::new();
+    ///
+    /// assert_eq!(set.is_subset(&sup), true);
+    /// set.insert(2);
+    /// assert_eq!(set.is_subset(&sup), true);
+    /// set.insert(4);
+    /// assert_eq!(set.is_subset(&sup), false);
+    /// ```
+    ///
+    /// `other` may be any [`SetLike`](trait.SetLike.html) collection:
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    /// use std::collections::BTreeSet;
+    ///
+    /// let sup: BTreeSet<_> = [1, 2, 3].iter().cloned().collect();
+    /// let set: LinearSet<_> = [3, 1].iter().cloned().collect();
+    ///
+    /// assert!(set.is_subset(&sup));
+    /// ```
+    pub fn is_subset<S>(&self, other: &S) -> bool
+    where
+        S: SetLike<T> + ?Sized,
+    {
+        self.iter().all(|v| other.contains(v))
+    }
+
+    /// Returns `true` if the set is a superset of another.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    ///
+    /// let sub: LinearSet<_> = [1, 2].iter().cloned().collect();
+    /// let mut set = LinearSet::new();
+    ///
+    /// assert_eq!(set.is_superset(&sub), false);
+    ///
+    /// set.insert(0);
+    /// set.insert(1);
+    /// assert_eq!(set.is_superset(&sub), false);
+    ///
+    /// set.insert(2);
+    /// assert_eq!(set.is_superset(&sub), true);
+    /// ```
+    #[inline]
+    pub fn is_superset(&self, other: &LinearSet<T>) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Adds a value to the set.
+    ///
+    /// If the set did not have a value present, `true` is returned.
+    ///
+    /// If the set did have this key present, `false` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    ///
+    /// let mut set = LinearSet::new();
+    ///
+    /// assert_eq!(set.insert(2), true);
+    /// assert_eq!(set.insert(2), false);
+    /// assert_eq!(set.len(), 1);
+    /// ```
     pub fn insert(&mut self, value: T) -> bool {
         self.map.insert(value, ()).is_none()
     }
 
+    /// Adds a value to the set without checking whether it is already present, and returns a
+    /// reference to it.
+    ///
+    /// This runs in `O(1)` amortized time. It is a logic error for the set to already contain
+    /// an equal value; this is only checked in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    ///
+    /// let mut set = LinearSet::new();
+    /// for id in 0..100 {
+    ///     set.insert_unique_unchecked(id);
+    /// }
+    /// assert_eq!(set.len(), 100);
+    /// ```
+    pub fn insert_unique_unchecked(&mut self, value: T) -> &T {
+        debug_assert!(!self.contains(&value), "set already contains the value");
+        let storage = &mut self.map.storage;
+        storage.push((value, ()));
+        &storage.last().unwrap().0
+    }
+
     /// Removes a value from the set. Returns `true` if the value was
     /// present in the set.
     ///
@@ -496,16 +1026,705 @@ where
     /// assert_eq!(set.remove(&2), true);
     /// assert_eq!(set.remove(&2), false);
     /// ```
+    pub fn remove<Q>(&mut self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: ?Sized + Eq,
+    {
+        self.map.remove(value).is_some()
+    }
+
+    /// Returns a reference to the value in the set, if any, that is equal to the given value.
+    ///
+    /// The value may be any borrowed form of the set's value type, but
+    /// `Eq` on the borrowed form *must* match those for
+    /// the value type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    ///
+    /// let set: LinearSet<_> = [1, 2, 3].iter().cloned().collect();
+    /// assert_eq!(set.get(&2), Some(&2));
+    /// assert_eq!(set.get(&4), None);
+    /// ```
+    pub fn get<Q>(&self, value: &Q) -> Option<&T>
+    where
+        T: Borrow<Q>,
+        Q: ?Sized + Eq,
+    {
+        self.map.get_key_value(value).map(|(k, _)| k)
+    }
+
+    /// Removes and returns the value in the set, if any, that is equal to the given one.
+    ///
+    /// The value may be any borrowed form of the set's value type, but
+    /// `Eq` on the borrowed form *must* match those for
+    /// the value type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    ///
+    /// let mut set: LinearSet<_> = [1, 2, 3].iter().cloned().collect();
+    /// assert_eq!(set.take(&2), Some(2));
+    /// assert_eq!(set.take(&2), None);
+    /// ```
+    pub fn take<Q>(&mut self, value: &Q) -> Option<T>
+    where
+        T: Borrow<Q>,
+        Q: ?Sized + Eq,
+    {
+        self.map.remove_entry(value).map(|(k, _)| k)
+    }
+
+    /// Adds a value to the set, replacing the existing value, if any, that is equal to the given
+    /// one. Returns the replaced value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    ///
+    /// let mut set = LinearSet::new();
+    /// set.insert(Vec::<i32>::new());
+    ///
+    /// assert_eq!(set.get(&[][..]).unwrap().capacity(), 0);
+    /// set.replace(Vec::with_capacity(10));
+    /// assert_eq!(set.get(&[][..]).unwrap().capacity(), 10);
+    /// ```
+    pub fn replace(&mut self, value: T) -> Option<T> {
+        let storage = &mut self.map.storage;
+        match storage.iter().position(|(k, _)| *k == value) {
+            Some(index) => Some(mem::replace(&mut storage[index].0, value)),
+            None => {
+                storage.push((value, ()));
+                None
+            }
+        }
+    }
+
+    /// Inserts the given value into the set if it is not present, then returns a reference to
+    /// the value in the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    ///
+    /// let mut set: LinearSet<_> = [1, 2, 3].iter().cloned().collect();
+    /// assert_eq!(set.len(), 3);
+    /// assert_eq!(set.get_or_insert(2), &2);
+    /// assert_eq!(set.get_or_insert(100), &100);
+    /// assert_eq!(set.len(), 4);
+    /// ```
+    pub fn get_or_insert(&mut self, value: T) -> &T {
+        let index = match self.map.storage.iter().position(|(k, _)| *k == value) {
+            Some(index) => index,
+            None => {
+                self.map.storage.push((value, ()));
+                self.map.storage.len() - 1
+            }
+        };
+        &self.map.storage[index].0
+    }
+
+    /// Inserts a value computed from `f` into the set if the given `value` is not present, then
+    /// returns a reference to the value in the set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `f` returns a value that is not equal to `value`. The set is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    ///
+    /// let mut set: LinearSet<String> = ["cat", "dog", "horse"]
+    ///     .iter().map(|&pet| pet.to_owned()).collect();
+    ///
+    /// assert_eq!(set.len(), 3);
+    /// for &pet in &["cat", "dog", "fish"] {
+    ///     let value = set.get_or_insert_with(pet, str::to_owned);
+    ///     assert_eq!(value, pet);
+    /// }
+    /// assert_eq!(set.len(), 4);
+    /// ```
+    pub fn get_or_insert_with<Q, F>(&mut self, value: &Q, f: F) -> &T
+    where
+        T: Borrow<Q>,
+        Q: ?Sized + Eq,
+        F: FnOnce(&Q) -> T,
+    {
+        let storage = &mut self.map.storage;
+        let index = match storage.iter().position(|(k, _)| k.borrow() == value) {
+            Some(index) => index,
+            None => {
+                let new = f(value);
+                assert!(new.borrow() == value, "new value is not equal");
+                storage.push((new, ()));
+                storage.len() - 1
+            }
+        };
+        &storage[index].0
+    }
+
+    /// Returns the given value's corresponding entry in the set for in-place manipulation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::set::{Entry, LinearSet};;
+    ///
+    /// let mut singles = LinearSet::new();
+    /// let mut dupes = LinearSet::new();
+    ///
+    /// for ch in "a short treatise on fungi".chars() {
+    ///     if let Entry::Vacant(dupe_entry) = dupes.entry(ch) {
+    ///         // We haven't already seen a duplicate, so
+    ///         // check if we've at least seen it once.
+    ///         match singles.entry(ch) {
+    ///             Entry::Vacant(single_entry) => {
+    ///                 // We found a new character for the first time.
+    ///                 single_entry.insert();
+    ///             }
+    ///             Entry::Occupied(single_entry) => {
+    ///                 // We've already seen this once, "move" it to dupes.
+    ///                 single_entry.remove();
+    ///                 dupe_entry.insert();
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// assert!(!singles.contains(&'t') && dupes.contains(&'t'));
+    /// assert!(singles.contains(&'u') && !dupes.contains(&'u'));
+    /// assert!(!singles.contains(&'v') && !dupes.contains(&'v'));
+    /// ```
+    pub fn entry(&mut self, value: T) -> Entry<'_, T> {
+        match self.map.entry(value) {
+            super::Entry::Occupied(inner) => Entry::Occupied(OccupiedEntry { inner }),
+            super::Entry::Vacant(inner) => Entry::Vacant(VacantEntry { inner }),
+        }
+    }
+}
+
+impl<T> LinearSet<T>
+where
+    T: Eq + Hash,
+{
+    /// Returns `true` if the set is equal to `other`, like `==`, but looks the
+    /// values up in a temporary hash table, which runs in `O(n)` expected time
+    /// instead of `O(n²)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    ///
+    /// let a: LinearSet<_> = (0..100).collect();
+    /// let b: LinearSet<_> = (0..100).rev().collect();
+    /// assert!(a.eq_hashed(&b));
+    /// ```
+    pub fn eq_hashed(&self, other: &LinearSet<T>) -> bool {
+        self.len() == other.len() && self.is_subset_hashed(other)
+    }
+
+    /// Returns `true` if the set is a subset of another, like `is_subset`, but
+    /// looks the values up in a temporary hash table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    ///
+    /// let sup: LinearSet<_> = (0..100).collect();
+    /// let set: LinearSet<_> = (10..20).collect();
+    /// assert!(set.is_subset_hashed(&sup));
+    /// assert!(!sup.is_subset_hashed(&set));
+    /// ```
+    pub fn is_subset_hashed(&self, other: &LinearSet<T>) -> bool {
+        is_subset_with(self, &other.iter().collect::<HashSet<_>>())
+    }
+
+    /// Returns `true` if the set has no elements in common with `other`, like
+    /// `is_disjoint`, but looks the values up in a temporary hash table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    ///
+    /// let a: LinearSet<_> = (0..100).collect();
+    /// let b: LinearSet<_> = (100..200).collect();
+    /// assert!(a.is_disjoint_hashed(&b));
+    /// ```
+    pub fn is_disjoint_hashed(&self, other: &LinearSet<T>) -> bool {
+        is_disjoint_with(self, &other.iter().collect::<HashSet<_>>())
+    }
+
+    /// Visit the values representing the intersection, like `intersection`,
+    /// but looks the values up in a temporary hash table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    ///
+    /// let a: LinearSet<_> = (0..100).collect();
+    /// let b: LinearSet<_> = (90..200).collect();
+    /// assert_eq!(a.intersection_hashed(&b).count(), 10);
+    /// ```
+    pub fn intersection_hashed<'a>(
+        &'a self,
+        other: &'a LinearSet<T>,
+    ) -> impl Iterator<Item = &'a T> + 'a {
+        filter_with(self, other.iter().collect::<HashSet<_>>(), true)
+    }
+
+    /// Visit the values representing the difference, like `difference`, but
+    /// looks the values up in a temporary hash table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    ///
+    /// let a: LinearSet<_> = (0..100).collect();
+    /// let b: LinearSet<_> = (90..200).collect();
+    /// assert_eq!(a.difference_hashed(&b).count(), 90);
+    /// ```
+    pub fn difference_hashed<'a>(
+        &'a self,
+        other: &'a LinearSet<T>,
+    ) -> impl Iterator<Item = &'a T> + 'a {
+        filter_with(self, other.iter().collect::<HashSet<_>>(), false)
+    }
+
+    /// Visit the values representing the symmetric difference, like
+    /// `symmetric_difference`, but looks the values up in temporary hash
+    /// tables.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    ///
+    /// let a: LinearSet<_> = (0..100).collect();
+    /// let b: LinearSet<_> = (90..200).collect();
+    /// assert_eq!(a.symmetric_difference_hashed(&b).count(), 190);
+    /// ```
+    pub fn symmetric_difference_hashed<'a>(
+        &'a self,
+        other: &'a LinearSet<T>,
+    ) -> impl Iterator<Item = &'a T> + 'a {
+        self.difference_hashed(other)
+            .chain(other.difference_hashed(self))
+    }
+}
+
+impl<T> LinearSet<T>
+where
+    T: Ord,
+{
+    /// Returns `true` if the set is equal to `other`, like `==`, but compares
+    /// the values in ascending order, which runs in `O(n log n)` time instead
+    /// of `O(n²)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    ///
+    /// let a: LinearSet<_> = (0..100).collect();
+    /// let b: LinearSet<_> = (0..100).rev().collect();
+    /// assert!(a.eq_sorted(&b));
+    /// ```
+    pub fn eq_sorted(&self, other: &LinearSet<T>) -> bool {
+        self.len() == other.len() && self.sorted_iter().eq(other.sorted_iter())
+    }
+
+    /// Returns `true` if the set is a subset of another, like `is_subset`, but
+    /// looks the values up in a temporary sorted index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    ///
+    /// let sup: LinearSet<_> = (0..100).collect();
+    /// let set: LinearSet<_> = (10..20).collect();
+    /// assert!(set.is_subset_sorted(&sup));
+    /// assert!(!sup.is_subset_sorted(&set));
+    /// ```
+    pub fn is_subset_sorted(&self, other: &LinearSet<T>) -> bool {
+        is_subset_with(self, &other.iter().collect::<BTreeSet<_>>())
+    }
+
+    /// Returns `true` if the set has no elements in common with `other`, like
+    /// `is_disjoint`, but looks the values up in a temporary sorted index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    ///
+    /// let a: LinearSet<_> = (0..100).collect();
+    /// let b: LinearSet<_> = (100..200).collect();
+    /// assert!(a.is_disjoint_sorted(&b));
+    /// ```
+    pub fn is_disjoint_sorted(&self, other: &LinearSet<T>) -> bool {
+        is_disjoint_with(self, &other.iter().collect::<BTreeSet<_>>())
+    }
+
+    /// Visit the values representing the intersection, like `intersection`,
+    /// but looks the values up in a temporary sorted index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    ///
+    /// let a: LinearSet<_> = (0..100).collect();
+    /// let b: LinearSet<_> = (90..200).collect();
+    /// assert_eq!(a.intersection_sorted(&b).count(), 10);
+    /// ```
+    pub fn intersection_sorted<'a>(
+        &'a self,
+        other: &'a LinearSet<T>,
+    ) -> impl Iterator<Item = &'a T> + 'a {
+        filter_with(self, other.iter().collect::<BTreeSet<_>>(), true)
+    }
+
+    /// Visit the values representing the difference, like `difference`, but
+    /// looks the values up in a temporary sorted index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    ///
+    /// let a: LinearSet<_> = (0..100).collect();
+    /// let b: LinearSet<_> = (90..200).collect();
+    /// assert_eq!(a.difference_sorted(&b).count(), 90);
+    /// ```
+    pub fn difference_sorted<'a>(
+        &'a self,
+        other: &'a LinearSet<T>,
+    ) -> impl Iterator<Item = &'a T> + 'a {
+        filter_with(self, other.iter().collect::<BTreeSet<_>>(), false)
+    }
+
+    /// Visit the values representing the symmetric difference, like
+    /// `symmetric_difference`, but looks the values up in temporary sorted
+    /// indexes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    ///
+    /// let a: LinearSet<_> = (0..100).collect();
+    /// let b: LinearSet<_> = (90..200).collect();
+    /// assert_eq!(a.symmetric_difference_sorted(&b).count(), 190);
+    /// ```
+    pub fn symmetric_difference_sorted<'a>(
+        &'a self,
+        other: &'a LinearSet<T>,
+    ) -> impl Iterator<Item = &'a T> + 'a {
+        self.difference_sorted(other)
+            .chain(other.difference_sorted(self))
+    }
+}
+
+/// A collection that can be queried for membership.
+///
+/// The set operations of [`LinearSet`](struct.LinearSet.html) that only look values up in their
+/// other operand or iterate it, such as [`difference`](struct.LinearSet.html#method.difference),
+/// [`is_subset`](struct.LinearSet.html#method.is_subset) and
+/// [`union_with`](struct.LinearSet.html#method.union_with), accept any `SetLike` collection.
+/// Collections that can also be modified implement [`SetLikeMut`](trait.SetLikeMut.html).
+pub trait SetLike<T>: for<'a> SetLikeIter<'a, T> {
+    /// Returns `true` if the collection contains a value equal to the given one.
+    fn contains(&self, value: &T) -> bool;
+
+    /// Returns the number of values in the collection.
+    fn len(&self) -> usize;
+
+    /// Checks if the collection is empty.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an iterator yielding references to the collection's values.
+    fn iter(&self) -> <Self as SetLikeIter<'_, T>>::Iter;
+}
+
+/// The iterator type of a [`SetLike`](trait.SetLike.html) collection borrowed for `'a`.
+///
+/// The `Bounds` parameter is only there to limit the `for<'a>` bound on `SetLike` to the
+/// lifetimes the collection outlives, and should be left to its default.
+pub trait SetLikeIter<'a, T: 'a, Bounds = &'a Self> {
+    /// The iterator returned by [`SetLike::iter`](trait.SetLike.html#tymethod.iter).
+    type Iter: Iterator<Item = &'a T>;
+}
+
+/// A set that can be modified, implemented by `LinearSet`, `HashSet` and `BTreeSet`.
+///
+/// Code written against `SetLikeMut` can be run with whichever set suits the expected number of
+/// elements.
+///
+/// Values are removed and looked up by a `&Q`, which defaults to `&T`. A set implements
+/// `SetLikeMut<T, Q>` for any `Q` that its own lookups accept.
+///
+/// # Examples
+///
+/// ```
+/// use linear_map::set::{LinearSet, SetLike, SetLikeMut};;
+/// use std::collections::BTreeSet;
+///
+/// fn dedup_into<S: SetLikeMut<i32>>(set: &mut S, values: &[i32]) -> usize {
+///     values.iter().filter(|&&v| set.insert(v)).count()
+/// }
+///
+/// let mut linear = LinearSet::new();
+/// let mut sorted = BTreeSet::new();
+/// assert_eq!(dedup_into(&mut linear, &[3, 1, 3]), 2);
+/// assert_eq!(dedup_into(&mut sorted, &[3, 1, 3]), 2);
+/// assert_eq!(SetLike::len(&linear), SetLike::len(&sorted));
+/// ```
+pub trait SetLikeMut<T, Q: ?Sized = T>: SetLike<T> {
+    /// Adds a value to the set and returns `true` if it was not already present.
+    fn insert(&mut self, value: T) -> bool;
+
+    /// Removes a value from the set and returns `true` if it was present.
+    fn remove(&mut self, value: &Q) -> bool;
+
+    /// Returns a reference to the value in the set equal to the given one, first inserting the
+    /// result of `f` if there is none.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `f` returns a value that is not equal to `value`. The set is left unchanged.
+    fn get_or_insert_with<F: FnOnce(&Q) -> T>(&mut self, value: &Q, f: F) -> &T;
+}
+
+impl<'a, T: Eq> SetLikeIter<'a, T> for LinearSet<T> {
+    type Iter = Iter<'a, T>;
+}
+
+impl<T: Eq> SetLike<T> for LinearSet<T> {
+    fn contains(&self, value: &T) -> bool {
+        LinearSet::contains(self, value)
+    }
+
+    fn len(&self) -> usize {
+        LinearSet::len(self)
+    }
+
+    fn iter(&self) -> Iter<'_, T> {
+        LinearSet::iter(self)
+    }
+}
+
+impl<T, Q> SetLikeMut<T, Q> for LinearSet<T>
+where
+    T: Eq + Borrow<Q>,
+    Q: ?Sized + Eq,
+{
+    fn insert(&mut self, value: T) -> bool {
+        LinearSet::insert(self, value)
+    }
+
+    fn remove(&mut self, value: &Q) -> bool {
+        LinearSet::remove(self, value)
+    }
+
+    fn get_or_insert_with<F: FnOnce(&Q) -> T>(&mut self, value: &Q, f: F) -> &T {
+        LinearSet::get_or_insert_with(self, value, f)
+    }
+}
+
+impl<'a, T: Eq + Hash, S: BuildHasher> SetLikeIter<'a, T> for HashSet<T, S> {
+    type Iter = hash_set::Iter<'a, T>;
+}
+
+impl<T: Eq + Hash, S: BuildHasher> SetLike<T> for HashSet<T, S> {
+    fn contains(&self, value: &T) -> bool {
+        HashSet::contains(self, value)
+    }
+
+    fn len(&self) -> usize {
+        HashSet::len(self)
+    }
+
+    fn iter(&self) -> hash_set::Iter<'_, T> {
+        HashSet::iter(self)
+    }
+}
+
+impl<T, Q, S> SetLikeMut<T, Q> for HashSet<T, S>
+where
+    T: Eq + Hash + Borrow<Q>,
+    Q: ?Sized + Eq + Hash,
+    S: BuildHasher,
+{
+    fn insert(&mut self, value: T) -> bool {
+        HashSet::insert(self, value)
+    }
+
+    fn remove(&mut self, value: &Q) -> bool {
+        HashSet::remove(self, value)
+    }
+
+    fn get_or_insert_with<F: FnOnce(&Q) -> T>(&mut self, value: &Q, f: F) -> &T {
+        if !HashSet::contains(self, value) {
+            let new = f(value);
+            assert!(new.borrow() == value, "new value is not equal");
+            HashSet::insert(self, new);
+        }
+        HashSet::get(self, value).expect("value is in the set")
+    }
+}
+
+impl<'a, T: Ord> SetLikeIter<'a, T> for BTreeSet<T> {
+    type Iter = btree_set::Iter<'a, T>;
+}
+
+impl<T: Ord> SetLike<T> for BTreeSet<T> {
+    fn contains(&self, value: &T) -> bool {
+        BTreeSet::contains(self, value)
+    }
+
+    fn len(&self) -> usize {
+        BTreeSet::len(self)
+    }
+
+    fn iter(&self) -> btree_set::Iter<'_, T> {
+        BTreeSet::iter(self)
+    }
+}
+
+impl<T, Q> SetLikeMut<T, Q> for BTreeSet<T>
+where
+    T: Ord + Borrow<Q>,
+    Q: ?Sized + Ord,
+{
+    fn insert(&mut self, value: T) -> bool {
+        BTreeSet::insert(self, value)
+    }
+
+    fn remove(&mut self, value: &Q) -> bool {
+        BTreeSet::remove(self, value)
+    }
+
+    fn get_or_insert_with<F: FnOnce(&Q) -> T>(&mut self, value: &Q, f: F) -> &T {
+        if !BTreeSet::contains(self, value) {
+            let new = f(value);
+            assert!(new.borrow() == value, "new value is not equal");
+            BTreeSet::insert(self, new);
+        }
+        BTreeSet::get(self, value).expect("value is in the set")
+    }
+}
+
+impl<'a, 'b, K: Eq, V> SetLikeIter<'b, K> for KeySet<'a, K, V> {
+    type Iter = Keys<'b, K, V>;
+}
+
+impl<'a, K: Eq, V> SetLike<K> for KeySet<'a, K, V> {
+    fn contains(&self, key: &K) -> bool {
+        KeySet::contains(self, key)
+    }
+
+    fn len(&self) -> usize {
+        KeySet::len(self)
+    }
+
+    fn iter(&self) -> Keys<'_, K, V> {
+        KeySet::iter(self)
+    }
+}
+
+/// A sorted slice, looked up by binary search when used as a
+/// [`SetLike`](trait.SetLike.html) collection.
+///
+/// # Examples
+///
+/// ```
+/// use linear_map::set::{LinearSet, SortedSlice};;
+///
+/// let set: LinearSet<_> = [5, 1, 3].iter().cloned().collect();
+/// let odds = [1, 3, 5, 7, 9];
+/// assert!(set.is_subset(&SortedSlice::new(&odds)));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct SortedSlice<'a, T: 'a> {
+    slice: &'a [T],
+}
+
+impl<'a, T: Ord> SortedSlice<'a, T> {
+    /// Wraps the given slice, which must be sorted in ascending order.
+    ///
+    /// It is a logic error for the slice not to be sorted; this is only checked in debug builds.
+    pub fn new(slice: &'a [T]) -> Self {
+        debug_assert!(
+            slice.windows(2).all(|w| w[0] <= w[1]),
+            "slice is not sorted"
+        );
+        SortedSlice { slice }
+    }
+
+    /// Returns the wrapped slice.
+    pub fn as_slice(&self) -> &'a [T] {
+        self.slice
+    }
+}
+
+impl<'a, 'b, T: Ord> SetLikeIter<'b, T> for SortedSlice<'a, T> {
+    type Iter = slice::Iter<'b, T>;
+}
+
+impl<'a, T: Ord> SetLike<T> for SortedSlice<'a, T> {
+    fn contains(&self, value: &T) -> bool {
+        self.slice.binary_search(value).is_ok()
+    }
+
+    fn len(&self) -> usize {
+        self.slice.len()
+    }
 
-    pub fn remove<Q: ?Sized>(&mut self, value: &Q) -> bool
-    where
-        T: Borrow<Q>,
-        Q: Eq,
-    {
-        self.map.remove(value).is_some()
+    fn iter(&self) -> slice::Iter<'_, T> {
+        self.slice.iter()
     }
 }
 
+// The `_hashed` and `_sorted` set operations look the values up in a temporary `HashSet` or
+// `BTreeSet` of references to the other set's values.
+
+fn is_subset_with<'a, T: 'a, S: SetLike<&'a T>>(set: &'a LinearSet<T>, other: &S) -> bool {
+    set.map.storage.iter().all(|e| other.contains(&&e.0))
+}
+
+fn is_disjoint_with<'a, T: 'a, S: SetLike<&'a T>>(set: &'a LinearSet<T>, other: &S) -> bool {
+    !set.map.storage.iter().any(|e| other.contains(&&e.0))
+}
+
+fn filter_with<'a, T: 'a, S: SetLike<&'a T> + 'a>(
+    set: &'a LinearSet<T>,
+    other: S,
+    keep: bool,
+) -> impl Iterator<Item = &'a T> + 'a {
+    set.map
+        .storage
+        .iter()
+        .map(|e| &e.0)
+        .filter(move |value| other.contains(value) == keep)
+}
+
 impl<T> PartialEq for LinearSet<T>
 where
     T: Eq,
@@ -521,6 +1740,77 @@ where
 
 impl<T> Eq for LinearSet<T> where T: Eq {}
 
+impl<T, S> PartialEq<HashSet<T, S>> for LinearSet<T>
+where
+    T: Eq + Hash,
+    S: BuildHasher,
+{
+    fn eq(&self, other: &HashSet<T, S>) -> bool {
+        self.len() == other.len() && self.is_subset(other)
+    }
+}
+
+impl<T, S> PartialEq<LinearSet<T>> for HashSet<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher,
+{
+    fn eq(&self, other: &LinearSet<T>) -> bool {
+        other == self
+    }
+}
+
+impl<T> PartialEq<BTreeSet<T>> for LinearSet<T>
+where
+    T: Ord,
+{
+    fn eq(&self, other: &BTreeSet<T>) -> bool {
+        self.len() == other.len() && self.is_subset(other)
+    }
+}
+
+impl<T> PartialEq<LinearSet<T>> for BTreeSet<T>
+where
+    T: Ord,
+{
+    fn eq(&self, other: &LinearSet<T>) -> bool {
+        other == self
+    }
+}
+
+impl<T> Hash for LinearSet<T>
+where
+    T: Eq + Hash,
+{
+    /// Hashes the set independently of the order of its elements, so that sets that compare
+    /// equal also hash equal.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.map.hash(state)
+    }
+}
+
+impl<T> PartialOrd for LinearSet<T>
+where
+    T: Ord,
+{
+    /// Compares the sets' elements lexicographically in ascending order, independently of the
+    /// order they are stored in.
+    fn partial_cmp(&self, other: &LinearSet<T>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for LinearSet<T>
+where
+    T: Ord,
+{
+    /// Compares the sets' elements lexicographically in ascending order, independently of the
+    /// order they are stored in.
+    fn cmp(&self, other: &LinearSet<T>) -> Ordering {
+        self.map.cmp(&other.map)
+    }
+}
+
 impl<T> fmt::Debug for LinearSet<T>
 where
     T: Eq + fmt::Debug,
@@ -579,12 +1869,68 @@ impl<K: Eq> From<LinearSet<K>> for Vec<K> {
 }
 
 impl<K: Eq> From<Vec<K>> for LinearSet<K> {
+    /// Creates a LinearSet from a vector.
+    ///
+    /// It is a logic error for the vector to contain two equal elements. This is only checked
+    /// in debug builds; see [`LinearSet::try_from_vec`](struct.LinearSet.html#method.try_from_vec)
+    /// and [`LinearSet::from_vec_dedup`](struct.LinearSet.html#method.from_vec_dedup) for
+    /// vectors that may contain duplicates.
     fn from(other: Vec<K>) -> Self {
+        debug_assert!(
+            (1..other.len()).all(|i| !other[..i].contains(&other[i])),
+            "vector contains duplicate elements"
+        );
         unsafe { std::mem::transmute(other) }
     }
 }
 
-impl<'a, 'b, T> BitOr<&'b LinearSet<T>> for &'a LinearSet<T>
+impl<T: Eq, const N: usize> From<[T; N]> for LinearSet<T> {
+    /// Creates a LinearSet from an array, dropping duplicate elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    ///
+    /// let set = LinearSet::from([3, 1, 3, 2]);
+    /// assert_eq!(Vec::from(set), [3, 1, 2]);
+    /// ```
+    fn from(other: [T; N]) -> Self {
+        LinearSet::from_vec_dedup(Vec::from(other), DedupPolicy::LastWins)
+    }
+}
+
+impl<T: Eq, S> From<HashSet<T, S>> for LinearSet<T> {
+    /// Creates a LinearSet from a `HashSet`, in the `HashSet`'s iteration order.
+    fn from(other: HashSet<T, S>) -> Self {
+        LinearSet::from_unique(other)
+    }
+}
+
+impl<T: Eq> From<BTreeSet<T>> for LinearSet<T> {
+    /// Creates a LinearSet from a `BTreeSet`, with its elements sorted.
+    fn from(other: BTreeSet<T>) -> Self {
+        LinearSet::from_unique(other)
+    }
+}
+
+impl<T, S> From<LinearSet<T>> for HashSet<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    fn from(other: LinearSet<T>) -> Self {
+        other.into_iter().collect()
+    }
+}
+
+impl<T: Ord> From<LinearSet<T>> for BTreeSet<T> {
+    fn from(other: LinearSet<T>) -> Self {
+        other.into_iter().collect()
+    }
+}
+
+impl<T> BitOr<&LinearSet<T>> for &LinearSet<T>
 where
     T: Eq + Clone,
 {
@@ -615,96 +1961,450 @@ where
     }
 }
 
-impl<'a, 'b, T> BitAnd<&'b LinearSet<T>> for &'a LinearSet<T>
+impl<T> BitAnd<&LinearSet<T>> for &LinearSet<T>
+where
+    T: Eq + Clone,
+{
+    type Output = LinearSet<T>;
+
+    /// Returns the intersection of `self` and `rhs` as a new `LinearSet<T>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    ///
+    /// let a: LinearSet<_> = vec![1, 2, 3].into_iter().collect();
+    /// let b: LinearSet<_> = vec![2, 3, 4].into_iter().collect();
+    ///
+    /// let set = &a & &b;
+    ///
+    /// let mut i = 0;
+    /// let expected = [2, 3];
+    /// for x in &set {
+    ///     assert!(expected.contains(x));
+    ///     i += 1;
+    /// }
+    /// assert_eq!(i, expected.len());
+    /// ```
+    fn bitand(self, rhs: &LinearSet<T>) -> LinearSet<T> {
+        self.intersection(rhs).cloned().collect()
+    }
+}
+
+impl<T> BitXor<&LinearSet<T>> for &LinearSet<T>
+where
+    T: Eq + Clone,
+{
+    type Output = LinearSet<T>;
+
+    /// Returns the symmetric difference of `self` and `rhs` as a new `LinearSet<T>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    ///
+    /// let a: LinearSet<_> = vec![1, 2, 3].into_iter().collect();
+    /// let b: LinearSet<_> = vec![3, 4, 5].into_iter().collect();
+    ///
+    /// let set = &a ^ &b;
+    ///
+    /// let mut i = 0;
+    /// let expected = [1, 2, 4, 5];
+    /// for x in &set {
+    ///     assert!(expected.contains(x));
+    ///     i += 1;
+    /// }
+    /// assert_eq!(i, expected.len());
+    /// ```
+    fn bitxor(self, rhs: &LinearSet<T>) -> LinearSet<T> {
+        self.symmetric_difference(rhs).cloned().collect()
+    }
+}
+
+impl<T> Sub<&LinearSet<T>> for &LinearSet<T>
+where
+    T: Eq + Clone,
+{
+    type Output = LinearSet<T>;
+
+    /// Returns the difference of `self` and `rhs` as a new `LinearSet<T>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    ///
+    /// let a: LinearSet<_> = vec![1, 2, 3].into_iter().collect();
+    /// let b: LinearSet<_> = vec![3, 4, 5].into_iter().collect();
+    ///
+    /// let set = &a - &b;
+    ///
+    /// let mut i = 0;
+    /// let expected = [1, 2];
+    /// for x in &set {
+    ///     assert!(expected.contains(x));
+    ///     i += 1;
+    /// }
+    /// assert_eq!(i, expected.len());
+    /// ```
+    fn sub(self, rhs: &LinearSet<T>) -> LinearSet<T> {
+        self.difference(rhs).cloned().collect()
+    }
+}
+
+impl<T> BitOr<&LinearSet<T>> for LinearSet<T>
+where
+    T: Eq + Clone,
+{
+    type Output = LinearSet<T>;
+
+    /// Returns the union of `self` and `rhs`, reusing the storage of `self`.
+    fn bitor(mut self, rhs: &LinearSet<T>) -> LinearSet<T> {
+        self |= rhs;
+        self
+    }
+}
+
+impl<T> BitOr<LinearSet<T>> for LinearSet<T>
+where
+    T: Eq,
+{
+    type Output = LinearSet<T>;
+
+    /// Returns the union of `self` and `rhs`, reusing the storage of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    ///
+    /// let a: LinearSet<_> = vec![1, 2].into_iter().collect();
+    /// let b: LinearSet<_> = vec![2, 3].into_iter().collect();
+    /// let c: LinearSet<_> = vec![3, 4].into_iter().collect();
+    ///
+    /// let set = a | b | c;
+    /// assert_eq!(set, [1, 2, 3, 4].iter().cloned().collect::<LinearSet<_>>());
+    /// ```
+    fn bitor(mut self, rhs: LinearSet<T>) -> LinearSet<T> {
+        self |= rhs;
+        self
+    }
+}
+
+impl<T> BitAnd<&LinearSet<T>> for LinearSet<T>
+where
+    T: Eq,
+{
+    type Output = LinearSet<T>;
+
+    /// Returns the intersection of `self` and `rhs`, reusing the storage of `self`.
+    fn bitand(mut self, rhs: &LinearSet<T>) -> LinearSet<T> {
+        self &= rhs;
+        self
+    }
+}
+
+impl<T> BitAnd<LinearSet<T>> for LinearSet<T>
+where
+    T: Eq,
+{
+    type Output = LinearSet<T>;
+
+    /// Returns the intersection of `self` and `rhs`, reusing the storage of `self`.
+    fn bitand(mut self, rhs: LinearSet<T>) -> LinearSet<T> {
+        self &= rhs;
+        self
+    }
+}
+
+impl<T> BitXor<&LinearSet<T>> for LinearSet<T>
+where
+    T: Eq + Clone,
+{
+    type Output = LinearSet<T>;
+
+    /// Returns the symmetric difference of `self` and `rhs`, reusing the storage of `self`.
+    fn bitxor(mut self, rhs: &LinearSet<T>) -> LinearSet<T> {
+        self ^= rhs;
+        self
+    }
+}
+
+impl<T> BitXor<LinearSet<T>> for LinearSet<T>
+where
+    T: Eq,
+{
+    type Output = LinearSet<T>;
+
+    /// Returns the symmetric difference of `self` and `rhs`, reusing the storage of `self`.
+    fn bitxor(mut self, rhs: LinearSet<T>) -> LinearSet<T> {
+        self ^= rhs;
+        self
+    }
+}
+
+impl<T> Sub<&LinearSet<T>> for LinearSet<T>
+where
+    T: Eq,
+{
+    type Output = LinearSet<T>;
+
+    /// Returns the difference of `self` and `rhs`, reusing the storage of `self`.
+    fn sub(mut self, rhs: &LinearSet<T>) -> LinearSet<T> {
+        self -= rhs;
+        self
+    }
+}
+
+impl<T> Sub<LinearSet<T>> for LinearSet<T>
+where
+    T: Eq,
+{
+    type Output = LinearSet<T>;
+
+    /// Returns the difference of `self` and `rhs`, reusing the storage of `self`.
+    fn sub(mut self, rhs: LinearSet<T>) -> LinearSet<T> {
+        self -= rhs;
+        self
+    }
+}
+
+impl<T> BitOrAssign<&LinearSet<T>> for LinearSet<T>
+where
+    T: Eq + Clone,
+{
+    /// Adds the values of `rhs` to `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linear_map::set::LinearSet;;
+    ///
+    /// let mut a: LinearSet<_> = vec![1, 2, 3].into_iter().collect();
+    /// let b: LinearSet<_> = vec![3, 4, 5].into_iter().collect();
+    ///
+    /// a |= &b;
+    /// assert_eq!(a, [1, 2, 3, 4, 5].iter().cloned().collect::<LinearSet<_>>());
+    /// ```
+    fn bitor_assign(&mut self, rhs: &LinearSet<T>) {
+        self.union_with(rhs);
+    }
+}
+
+impl<T> BitOrAssign<LinearSet<T>> for LinearSet<T>
+where
+    T: Eq,
+{
+    /// Moves the values of `rhs` that are not in `self` into `self`.
+    fn bitor_assign(&mut self, rhs: LinearSet<T>) {
+        for value in rhs {
+            if !self.contains(&value) {
+                self.map.storage.push((value, ()));
+            }
+        }
+    }
+}
+
+impl<T> BitAndAssign<&LinearSet<T>> for LinearSet<T>
 where
-    T: Eq + Clone,
+    T: Eq,
 {
-    type Output = LinearSet<T>;
-
-    /// Returns the intersection of `self` and `rhs` as a new `LinearSet<T>`.
+    /// Removes the values of `self` that are not in `rhs`.
     ///
     /// # Examples
     ///
     /// ```
     /// use linear_map::set::LinearSet;;
     ///
-    /// let a: LinearSet<_> = vec![1, 2, 3].into_iter().collect();
+    /// let mut a: LinearSet<_> = vec![1, 2, 3].into_iter().collect();
     /// let b: LinearSet<_> = vec![2, 3, 4].into_iter().collect();
     ///
-    /// let set = &a & &b;
-    ///
-    /// let mut i = 0;
-    /// let expected = [2, 3];
-    /// for x in &set {
-    ///     assert!(expected.contains(x));
-    ///     i += 1;
-    /// }
-    /// assert_eq!(i, expected.len());
+    /// a &= &b;
+    /// assert_eq!(a, [2, 3].iter().cloned().collect::<LinearSet<_>>());
     /// ```
-    fn bitand(self, rhs: &LinearSet<T>) -> LinearSet<T> {
-        self.intersection(rhs).cloned().collect()
+    fn bitand_assign(&mut self, rhs: &LinearSet<T>) {
+        self.intersect_with(rhs);
     }
 }
 
-impl<'a, 'b, T> BitXor<&'b LinearSet<T>> for &'a LinearSet<T>
+impl<T> BitAndAssign<LinearSet<T>> for LinearSet<T>
 where
-    T: Eq + Clone,
+    T: Eq,
 {
-    type Output = LinearSet<T>;
+    /// Removes the values of `self` that are not in `rhs`.
+    fn bitand_assign(&mut self, rhs: LinearSet<T>) {
+        self.intersect_with(&rhs);
+    }
+}
 
-    /// Returns the symmetric difference of `self` and `rhs` as a new `LinearSet<T>`.
+impl<T> BitXorAssign<&LinearSet<T>> for LinearSet<T>
+where
+    T: Eq + Clone,
+{
+    /// Replaces `self` with the symmetric difference of `self` and `rhs`.
     ///
     /// # Examples
     ///
     /// ```
     /// use linear_map::set::LinearSet;;
     ///
-    /// let a: LinearSet<_> = vec![1, 2, 3].into_iter().collect();
+    /// let mut a: LinearSet<_> = vec![1, 2, 3].into_iter().collect();
     /// let b: LinearSet<_> = vec![3, 4, 5].into_iter().collect();
     ///
-    /// let set = &a ^ &b;
-    ///
-    /// let mut i = 0;
-    /// let expected = [1, 2, 4, 5];
-    /// for x in &set {
-    ///     assert!(expected.contains(x));
-    ///     i += 1;
-    /// }
-    /// assert_eq!(i, expected.len());
+    /// a ^= &b;
+    /// assert_eq!(a, [1, 2, 4, 5].iter().cloned().collect::<LinearSet<_>>());
     /// ```
-    fn bitxor(self, rhs: &LinearSet<T>) -> LinearSet<T> {
-        self.symmetric_difference(rhs).cloned().collect()
+    fn bitxor_assign(&mut self, rhs: &LinearSet<T>) {
+        self.symmetric_difference_with(rhs);
     }
 }
 
-impl<'a, 'b, T> Sub<&'b LinearSet<T>> for &'a LinearSet<T>
+impl<T> BitXorAssign<LinearSet<T>> for LinearSet<T>
 where
-    T: Eq + Clone,
+    T: Eq,
 {
-    type Output = LinearSet<T>;
+    /// Replaces `self` with the symmetric difference of `self` and `rhs`, moving the values
+    /// of `rhs` that are not in `self` into `self`.
+    fn bitxor_assign(&mut self, mut rhs: LinearSet<T>) {
+        self.retain(|value| rhs.take(value).is_none());
+        self.map.storage.append(&mut rhs.map.storage);
+    }
+}
 
-    /// Returns the difference of `self` and `rhs` as a new `LinearSet<T>`.
+impl<T> SubAssign<&LinearSet<T>> for LinearSet<T>
+where
+    T: Eq,
+{
+    /// Removes the values of `rhs` from `self`.
     ///
     /// # Examples
     ///
     /// ```
     /// use linear_map::set::LinearSet;;
     ///
-    /// let a: LinearSet<_> = vec![1, 2, 3].into_iter().collect();
+    /// let mut a: LinearSet<_> = vec![1, 2, 3].into_iter().collect();
     /// let b: LinearSet<_> = vec![3, 4, 5].into_iter().collect();
     ///
-    /// let set = &a - &b;
-    ///
-    /// let mut i = 0;
-    /// let expected = [1, 2];
-    /// for x in &set {
-    ///     assert!(expected.contains(x));
-    ///     i += 1;
-    /// }
-    /// assert_eq!(i, expected.len());
+    /// a -= &b;
+    /// assert_eq!(a, [1, 2].iter().cloned().collect::<LinearSet<_>>());
     /// ```
-    fn sub(self, rhs: &LinearSet<T>) -> LinearSet<T> {
-        self.difference(rhs).cloned().collect()
+    fn sub_assign(&mut self, rhs: &LinearSet<T>) {
+        self.difference_with(rhs);
+    }
+}
+
+impl<T> SubAssign<LinearSet<T>> for LinearSet<T>
+where
+    T: Eq,
+{
+    /// Removes the values of `rhs` from `self`.
+    fn sub_assign(&mut self, rhs: LinearSet<T>) {
+        self.difference_with(&rhs);
+    }
+}
+
+/// A view into a single entry in a `LinearSet`, which may either be vacant or occupied.
+///
+/// See [`LinearSet::entry`](struct.LinearSet.html#method.entry) for details.
+pub enum Entry<'a, T: 'a> {
+    /// An occupied entry.
+    Occupied(OccupiedEntry<'a, T>),
+
+    /// A vacant entry.
+    Vacant(VacantEntry<'a, T>),
+}
+
+/// A view into an occupied entry in a `LinearSet`.
+///
+/// See [`LinearSet::entry`](struct.LinearSet.html#method.entry) for details.
+pub struct OccupiedEntry<'a, T: 'a> {
+    inner: super::OccupiedEntry<'a, T, ()>,
+}
+
+/// A view into a vacant entry in a `LinearSet`.
+///
+/// See [`LinearSet::entry`](struct.LinearSet.html#method.entry) for details.
+pub struct VacantEntry<'a, T: 'a> {
+    inner: super::VacantEntry<'a, T, ()>,
+}
+
+impl<'a, T> Entry<'a, T> {
+    /// Sets the value of the entry, and returns an `OccupiedEntry`.
+    pub fn insert(self) -> OccupiedEntry<'a, T> {
+        match self {
+            Entry::Occupied(entry) => entry,
+            Entry::Vacant(entry) => OccupiedEntry {
+                inner: entry.inner.insert_entry(()),
+            },
+        }
+    }
+
+    /// Ensures a value is in the entry by inserting if it was vacant.
+    pub fn or_insert(self) {
+        if let Entry::Vacant(entry) = self {
+            entry.insert();
+        }
+    }
+
+    /// Returns a reference to this entry's value.
+    pub fn get(&self) -> &T {
+        match *self {
+            Entry::Occupied(ref entry) => entry.get(),
+            Entry::Vacant(ref entry) => entry.get(),
+        }
+    }
+}
+
+impl<'a, T> OccupiedEntry<'a, T> {
+    /// Returns a reference to the value in the entry.
+    pub fn get(&self) -> &T {
+        self.inner.key()
+    }
+
+    /// Takes the value out of the entry, and returns it.
+    pub fn remove(self) -> T {
+        self.inner.remove_entry().0
+    }
+}
+
+impl<'a, T> VacantEntry<'a, T> {
+    /// Returns a reference to the value that would be used when inserting through the
+    /// `VacantEntry`.
+    pub fn get(&self) -> &T {
+        self.inner.key()
+    }
+
+    /// Takes ownership of the value.
+    pub fn into_value(self) -> T {
+        self.inner.into_key()
+    }
+
+    /// Sets the value of the entry with the `VacantEntry`'s value.
+    pub fn insert(self) {
+        self.inner.insert(());
+    }
+}
+
+impl<'a, T: fmt::Debug> fmt::Debug for Entry<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Entry::Occupied(ref entry) => f.debug_tuple("Entry").field(entry).finish(),
+            Entry::Vacant(ref entry) => f.debug_tuple("Entry").field(entry).finish(),
+        }
+    }
+}
+
+impl<'a, T: fmt::Debug> fmt::Debug for OccupiedEntry<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("OccupiedEntry").field(self.get()).finish()
+    }
+}
+
+impl<'a, T: fmt::Debug> fmt::Debug for VacantEntry<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("VacantEntry").field(self.get()).finish()
     }
 }
 
@@ -713,6 +2413,11 @@ pub struct Iter<'a, K: 'a> {
     iter: Keys<'a, K, ()>,
 }
 
+/// LinearSet iterator in ascending order
+pub struct SortedIter<'a, K: 'a> {
+    iter: super::SortedIter<'a, K, ()>,
+}
+
 /// LinearSet move iterator
 pub struct IntoIter<K> {
     iter: super::IntoIter<K, ()>,
@@ -723,20 +2428,25 @@ pub struct Drain<'a, K: 'a> {
     iter: super::Drain<'a, K, ()>,
 }
 
+/// LinearSet iterator removing the elements that match a predicate
+pub struct ExtractIf<'a, K: 'a, F> {
+    iter: super::ExtractIf<'a, K, (), F>,
+}
+
 /// Intersection iterator
-pub struct Intersection<'a, T: 'a> {
+pub struct Intersection<'a, T: 'a, S: 'a + ?Sized = LinearSet<T>> {
     // iterator of the first set
     iter: Iter<'a, T>,
     // the second set
-    other: &'a LinearSet<T>,
+    other: &'a S,
 }
 
 /// Difference iterator
-pub struct Difference<'a, T: 'a> {
+pub struct Difference<'a, T: 'a, S: 'a + ?Sized = LinearSet<T>> {
     // iterator of the first set
     iter: Iter<'a, T>,
     // the second set
-    other: &'a LinearSet<T>,
+    other: &'a S,
 }
 
 /// Symmetric difference iterator.
@@ -817,6 +2527,51 @@ impl<'a, K> ExactSizeIterator for Iter<'a, K> {
         self.iter.len()
     }
 }
+impl<'a, K> DoubleEndedIterator for Iter<'a, K> {
+    fn next_back(&mut self) -> Option<&'a K> {
+        self.iter.next_back()
+    }
+}
+impl<'a, K> FusedIterator for Iter<'a, K> {}
+impl<'a, K: fmt::Debug> fmt::Debug for Iter<'a, K> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<'a, K> Clone for SortedIter<'a, K> {
+    fn clone(&self) -> SortedIter<'a, K> {
+        SortedIter {
+            iter: self.iter.clone(),
+        }
+    }
+}
+impl<'a, K> Iterator for SortedIter<'a, K> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<&'a K> {
+        self.iter.next().map(|(k, _)| k)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+impl<'a, K> ExactSizeIterator for SortedIter<'a, K> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+impl<'a, K> DoubleEndedIterator for SortedIter<'a, K> {
+    fn next_back(&mut self) -> Option<&'a K> {
+        self.iter.next_back().map(|(k, _)| k)
+    }
+}
+impl<'a, K> FusedIterator for SortedIter<'a, K> {}
+impl<'a, K: fmt::Debug> fmt::Debug for SortedIter<'a, K> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
 
 impl<K> Iterator for IntoIter<K> {
     type Item = K;
@@ -833,6 +2588,24 @@ impl<K> ExactSizeIterator for IntoIter<K> {
         self.iter.len()
     }
 }
+impl<K> DoubleEndedIterator for IntoIter<K> {
+    fn next_back(&mut self) -> Option<K> {
+        self.iter.next_back().map(|(k, _)| k)
+    }
+}
+impl<K> FusedIterator for IntoIter<K> {}
+impl<K: Clone> Clone for IntoIter<K> {
+    fn clone(&self) -> IntoIter<K> {
+        IntoIter {
+            iter: self.iter.clone(),
+        }
+    }
+}
+impl<K: fmt::Debug> fmt::Debug for IntoIter<K> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter.as_slice().keys()).finish()
+    }
+}
 
 impl<'a, K> Iterator for Drain<'a, K> {
     type Item = K;
@@ -849,9 +2622,41 @@ impl<'a, K> ExactSizeIterator for Drain<'a, K> {
         self.iter.len()
     }
 }
+impl<'a, K> DoubleEndedIterator for Drain<'a, K> {
+    fn next_back(&mut self) -> Option<K> {
+        self.iter.next_back().map(|(k, _)| k)
+    }
+}
+impl<'a, K> FusedIterator for Drain<'a, K> {}
+impl<'a, K: fmt::Debug> fmt::Debug for Drain<'a, K> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let iter = self.iter.iter.as_slice().iter().map(|e| &e.0);
+        f.debug_list().entries(iter).finish()
+    }
+}
+
+impl<'a, K, F> Iterator for ExtractIf<'a, K, F>
+where
+    F: FnMut(&K) -> bool,
+{
+    type Item = K;
+
+    fn next(&mut self) -> Option<K> {
+        self.iter.next_matching(|pred, k, _| pred(k)).map(|e| e.0)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.remaining()
+    }
+}
+impl<'a, K, F> FusedIterator for ExtractIf<'a, K, F> where F: FnMut(&K) -> bool {}
+impl<'a, K, F> fmt::Debug for ExtractIf<'a, K, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ExtractIf").finish_non_exhaustive()
+    }
+}
 
-impl<'a, T> Clone for Intersection<'a, T> {
-    fn clone(&self) -> Intersection<'a, T> {
+impl<'a, T, S: ?Sized> Clone for Intersection<'a, T, S> {
+    fn clone(&self) -> Intersection<'a, T, S> {
         Intersection {
             iter: self.iter.clone(),
             ..*self
@@ -859,9 +2664,10 @@ impl<'a, T> Clone for Intersection<'a, T> {
     }
 }
 
-impl<'a, T> Iterator for Intersection<'a, T>
+impl<'a, T, S> Iterator for Intersection<'a, T, S>
 where
     T: Eq,
+    S: SetLike<T> + ?Sized,
 {
     type Item = &'a T;
 
@@ -884,8 +2690,44 @@ where
     }
 }
 
-impl<'a, T> Clone for Difference<'a, T> {
-    fn clone(&self) -> Difference<'a, T> {
+impl<'a, T, S> DoubleEndedIterator for Intersection<'a, T, S>
+where
+    T: Eq,
+    S: SetLike<T> + ?Sized,
+{
+    fn next_back(&mut self) -> Option<&'a T> {
+        loop {
+            match self.iter.next_back() {
+                None => return None,
+                Some(elt) => {
+                    if self.other.contains(elt) {
+                        return Some(elt);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<'a, T, S> FusedIterator for Intersection<'a, T, S>
+where
+    T: Eq,
+    S: SetLike<T> + ?Sized,
+{
+}
+
+impl<'a, T, S> fmt::Debug for Intersection<'a, T, S>
+where
+    T: Eq + fmt::Debug,
+    S: SetLike<T> + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<'a, T, S: ?Sized> Clone for Difference<'a, T, S> {
+    fn clone(&self) -> Difference<'a, T, S> {
         Difference {
             iter: self.iter.clone(),
             ..*self
@@ -893,9 +2735,10 @@ impl<'a, T> Clone for Difference<'a, T> {
     }
 }
 
-impl<'a, T> Iterator for Difference<'a, T>
+impl<'a, T, S> Iterator for Difference<'a, T, S>
 where
     T: Eq,
+    S: SetLike<T> + ?Sized,
 {
     type Item = &'a T;
 
@@ -918,6 +2761,42 @@ where
     }
 }
 
+impl<'a, T, S> DoubleEndedIterator for Difference<'a, T, S>
+where
+    T: Eq,
+    S: SetLike<T> + ?Sized,
+{
+    fn next_back(&mut self) -> Option<&'a T> {
+        loop {
+            match self.iter.next_back() {
+                None => return None,
+                Some(elt) => {
+                    if !self.other.contains(elt) {
+                        return Some(elt);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<'a, T, S> FusedIterator for Difference<'a, T, S>
+where
+    T: Eq,
+    S: SetLike<T> + ?Sized,
+{
+}
+
+impl<'a, T, S> fmt::Debug for Difference<'a, T, S>
+where
+    T: Eq + fmt::Debug,
+    S: SetLike<T> + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
 impl<'a, T> Clone for SymmetricDifference<'a, T> {
     fn clone(&self) -> SymmetricDifference<'a, T> {
         SymmetricDifference {
@@ -940,6 +2819,26 @@ where
     }
 }
 
+impl<'a, T> DoubleEndedIterator for SymmetricDifference<'a, T>
+where
+    T: Eq,
+{
+    fn next_back(&mut self) -> Option<&'a T> {
+        self.iter.next_back()
+    }
+}
+
+impl<'a, T> FusedIterator for SymmetricDifference<'a, T> where T: Eq {}
+
+impl<'a, T> fmt::Debug for SymmetricDifference<'a, T>
+where
+    T: Eq + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
 impl<'a, T> Clone for Union<'a, T> {
     fn clone(&self) -> Union<'a, T> {
         Union {
@@ -962,6 +2861,26 @@ where
     }
 }
 
+impl<'a, T> DoubleEndedIterator for Union<'a, T>
+where
+    T: Eq,
+{
+    fn next_back(&mut self) -> Option<&'a T> {
+        self.iter.next_back()
+    }
+}
+
+impl<'a, T> FusedIterator for Union<'a, T> where T: Eq {}
+
+impl<'a, T> fmt::Debug for Union<'a, T>
+where
+    T: Eq + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
 #[allow(dead_code)]
 fn assert_covariance() {
     fn set<'new>(v: LinearSet<&'static str>) -> LinearSet<&'new str> {
@@ -0,0 +1,92 @@
+extern crate linear_map;
+
+use linear_map::bag::LinearBag;
+use linear_map::LinearMap;
+
+#[test]
+fn test_add_and_remove() {
+    let mut bag = LinearBag::new();
+    assert_eq!(bag.add('a'), 1);
+    assert_eq!(bag.add_n('a', 2), 3);
+    assert_eq!(bag.add_n('b', 0), 0);
+    assert_eq!(bag.len(), 1);
+    assert_eq!(bag.total(), 3);
+
+    assert!(bag.remove_one(&'a'));
+    assert_eq!(bag.count(&'a'), 2);
+    assert!(!bag.remove_one(&'b'));
+
+    bag.add('b');
+    assert_eq!(bag.remove_n(&'a', 5), 2);
+    assert!(!bag.contains(&'a'));
+    assert_eq!(bag.remove_all(&'b'), 1);
+    assert!(bag.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "count overflow")]
+fn test_add_n_overflow() {
+    let mut bag = LinearBag::new();
+    bag.add_n('a', usize::MAX);
+    bag.add('a');
+}
+
+#[test]
+fn test_most_common() {
+    let bag: LinearBag<_> = "mississippi".chars().collect();
+    assert_eq!(bag.most_common(0), []);
+    assert_eq!(bag.most_common(1), [(&'i', 4)]);
+    assert_eq!(
+        bag.most_common(10),
+        [(&'i', 4), (&'s', 4), (&'p', 2), (&'m', 1)]
+    );
+}
+
+#[test]
+fn test_iter() {
+    let bag: LinearBag<_> = vec![2, 1, 2, 3, 2].into_iter().collect();
+    let iter = bag.iter();
+    assert_eq!(iter.size_hint(), (3, None));
+    assert_eq!(iter.cloned().collect::<Vec<_>>(), [2, 2, 2, 1, 3]);
+    assert_eq!(
+        bag.counts().collect::<Vec<_>>(),
+        [(&2, &3), (&1, &1), (&3, &1)]
+    );
+    assert_eq!((&bag).into_iter().count(), bag.total());
+    assert_eq!(LinearBag::<i32>::new().iter().next(), None);
+}
+
+#[test]
+fn test_ops() {
+    let a: LinearBag<_> = "aabc".chars().collect();
+    let b: LinearBag<_> = "abbd".chars().collect();
+
+    let sum = &a + &b;
+    assert_eq!(sum, "aaabbbcd".chars().collect());
+    assert_eq!(&a - &b, "ac".chars().collect());
+    assert_eq!(&b - &a, "bd".chars().collect());
+    assert_eq!(&a & &b, "ab".chars().collect());
+    assert_eq!(&a | &b, "aabbcd".chars().collect());
+}
+
+#[test]
+fn test_map_conversions() {
+    let mut map = LinearMap::new();
+    map.insert("x", 2);
+    map.insert("y", 0);
+    let bag = LinearBag::from(map);
+    assert_eq!(bag.len(), 1);
+    assert_eq!(bag.as_map().get(&"x"), Some(&2));
+
+    let map: LinearMap<_, _> = bag.clone().into();
+    assert_eq!(map, bag.into_map());
+}
+
+#[test]
+fn test_eq_and_debug() {
+    let a: LinearBag<_> = vec![1, 2, 1].into_iter().collect();
+    let b: LinearBag<_> = vec![2, 1, 1].into_iter().collect();
+    assert_eq!(a, b);
+    assert_ne!(a, vec![1, 2].into_iter().collect());
+    assert_eq!(format!("{:?}", a), "{1: 2, 2: 1}");
+}
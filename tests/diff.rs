@@ -0,0 +1,59 @@
+#[macro_use]
+extern crate linear_map;
+
+use linear_map::diff::Change::{Added, Modified, Removed};
+use linear_map::diff::Patch;
+use linear_map::LinearMap;
+
+#[test]
+fn test_diff() {
+    let old = linear_map! { 1 => 'a', 2 => 'b', 3 => 'c' };
+    let new = linear_map! { 4 => 'd', 3 => 'C', 1 => 'a' };
+
+    let changes: Vec<_> = old.diff(&new).collect();
+    assert_eq!(
+        changes,
+        [Removed(&2, &'b'), Modified(&3, &'c', &'C'), Added(&4, &'d')]
+    );
+    assert_eq!(old.diff(&old).count(), 0);
+    assert_eq!(changes[1].key(), &&3);
+    assert_eq!(changes[0].invert(), Added(&2, &'b'));
+    assert_eq!(changes[1].invert(), Modified(&3, &'C', &'c'));
+}
+
+#[test]
+fn test_patch() {
+    let old = linear_map! { 1 => 'a', 2 => 'b', 3 => 'c' };
+    let new = linear_map! { 4 => 'd', 3 => 'C', 1 => 'a' };
+
+    let patch = Patch::between(&old, &new);
+    assert_eq!(patch.len(), 3);
+    assert_eq!(patch.changes()[2], Added(4, 'd'));
+
+    let mut map = old.clone();
+    patch.clone().apply(&mut map);
+    assert_eq!(map, new);
+
+    let undo = patch.invert();
+    assert_eq!(undo.changes()[0], Removed(4, 'd'));
+    undo.apply(&mut map);
+    assert_eq!(map, old);
+
+    assert!(Patch::between(&old, &old).is_empty());
+}
+
+#[test]
+fn test_patch_build() {
+    let mut patch: Patch<i32, char> = Patch::new();
+    patch.push(Added(1, 'a'));
+    patch.extend(vec![Added(2, 'b'), Removed(1, 'a')]);
+    assert_eq!(patch.iter().count(), 3);
+
+    let mut map = LinearMap::new();
+    patch.apply(&mut map);
+    assert_eq!(map, linear_map! { 2 => 'b' });
+
+    let patch: Patch<_, _> = vec![Modified(2, 'b', 'B')].into();
+    let changes: Vec<_> = patch.into_iter().collect();
+    assert_eq!(changes, [Modified(2, 'b', 'B')]);
+}
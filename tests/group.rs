@@ -0,0 +1,54 @@
+extern crate linear_map;
+
+use linear_map::group::{Counts, GroupedBy};
+use linear_map::LinearMap;
+
+#[test]
+fn test_group_by() {
+    let map = LinearMap::group_by(vec![3, 1, 4, 1, 5, 9, 2, 6], |n| n % 3);
+    assert_eq!(
+        map.iter().collect::<Vec<_>>(),
+        [
+            (&0, &vec![3, 9, 6]),
+            (&1, &vec![1, 4, 1]),
+            (&2, &vec![5, 2])
+        ]
+    );
+    assert!(LinearMap::group_by(Vec::<i32>::new(), |n| *n).is_empty());
+}
+
+#[test]
+fn test_counts() {
+    let map = LinearMap::counts("mississippi".chars());
+    assert_eq!(
+        map.iter().collect::<Vec<_>>(),
+        [(&'m', &1), (&'i', &4), (&'s', &4), (&'p', &2)]
+    );
+}
+
+#[test]
+fn test_fold_into() {
+    let words = ["a", "bb", "cc", "ddd", "e"];
+    let map = LinearMap::fold_into(words.iter(), |w| w.len(), String::new, |s, w| s.push_str(w));
+    assert_eq!(
+        map.iter().collect::<Vec<_>>(),
+        [
+            (&1, &"ae".to_string()),
+            (&2, &"bbcc".to_string()),
+            (&3, &"ddd".to_string())
+        ]
+    );
+}
+
+#[test]
+fn test_collect_adapters() {
+    let GroupedBy(map) = vec![("x", 1), ("y", 2), ("x", 3)].into_iter().collect();
+    assert_eq!(
+        map.iter().collect::<Vec<_>>(),
+        [(&"x", &vec![1, 3]), (&"y", &vec![2])]
+    );
+
+    let counts: Counts<_> = vec!['a', 'b', 'a'].into_iter().collect();
+    let map: LinearMap<_, _> = counts.into();
+    assert_eq!(map.iter().collect::<Vec<_>>(), [(&'a', &2), (&'b', &1)]);
+}
@@ -0,0 +1,27 @@
+#![cfg(feature = "hashbrown")]
+
+extern crate hashbrown;
+extern crate linear_map;
+
+use hashbrown::{HashMap, HashSet};
+use linear_map::set::LinearSet;
+use linear_map::LinearMap;
+
+#[test]
+fn test_map_conversions() {
+    let map = LinearMap::from([(3, 'c'), (1, 'a'), (2, 'b')]);
+
+    let hash_map: HashMap<_, _> = map.clone().into();
+    assert_eq!(hash_map.len(), 3);
+    assert_eq!(hash_map[&1], 'a');
+    assert_eq!(LinearMap::from(hash_map), map);
+}
+
+#[test]
+fn test_set_conversions() {
+    let set = LinearSet::from([3, 1, 2]);
+
+    let hash_set: HashSet<_> = set.clone().into();
+    assert!(hash_set.contains(&3));
+    assert_eq!(LinearSet::from(hash_set), set);
+}
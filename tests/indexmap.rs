@@ -0,0 +1,29 @@
+#![cfg(feature = "indexmap")]
+
+extern crate indexmap;
+extern crate linear_map;
+
+use indexmap::{IndexMap, IndexSet};
+use linear_map::set::LinearSet;
+use linear_map::LinearMap;
+
+#[test]
+fn test_map_conversions() {
+    let map = LinearMap::from([(3, 'c'), (1, 'a'), (2, 'b')]);
+
+    let index_map: IndexMap<_, _> = map.clone().into();
+    assert_eq!(
+        index_map.iter().collect::<Vec<_>>(),
+        [(&3, &'c'), (&1, &'a'), (&2, &'b')]
+    );
+    assert_eq!(Vec::from(LinearMap::from(index_map)), Vec::from(map));
+}
+
+#[test]
+fn test_set_conversions() {
+    let set = LinearSet::from([3, 1, 2]);
+
+    let index_set: IndexSet<_> = set.clone().into();
+    assert_eq!(index_set.iter().collect::<Vec<_>>(), [&3, &1, &2]);
+    assert_eq!(Vec::from(LinearSet::from(index_set)), Vec::from(set));
+}
@@ -0,0 +1,92 @@
+#[macro_use]
+extern crate linear_map;
+
+use linear_map::join::EitherOrBoth::{Both, Left, Right};
+use linear_map::LinearMap;
+
+fn maps() -> (LinearMap<i32, char>, LinearMap<i32, &'static str>) {
+    let left = linear_map! { 1 => 'a', 2 => 'b', 3 => 'c', 4 => 'd' };
+    let right = linear_map! { 5 => "five", 3 => "three", 1 => "one", 6 => "six" };
+    (left, right)
+}
+
+#[test]
+fn test_inner_join() {
+    let (left, right) = maps();
+    let joined: Vec<_> = left.inner_join(&right).collect();
+    assert_eq!(joined, [(&1, &'a', &"one"), (&3, &'c', &"three")]);
+    let joined: Vec<_> = left.inner_join(&right).rev().collect();
+    assert_eq!(joined, [(&3, &'c', &"three"), (&1, &'a', &"one")]);
+    assert_eq!(left.inner_join(&LinearMap::<i32, ()>::new()).next(), None);
+
+    let joined = left.into_inner_join(right);
+    assert_eq!(joined.len(), 2);
+    assert_eq!(joined.get(&3), Some(&('c', "three")));
+    assert_eq!(joined.keys().cloned().collect::<Vec<_>>(), [1, 3]);
+}
+
+#[test]
+fn test_left_join() {
+    let (left, right) = maps();
+    let joined: Vec<_> = left.left_join(&right).collect();
+    assert_eq!(
+        joined,
+        [
+            (&1, &'a', Some(&"one")),
+            (&2, &'b', None),
+            (&3, &'c', Some(&"three")),
+            (&4, &'d', None),
+        ]
+    );
+    assert_eq!(left.left_join(&right).len(), 4);
+
+    let joined = left.into_left_join(right);
+    assert_eq!(joined.keys().cloned().collect::<Vec<_>>(), [1, 2, 3, 4]);
+    assert_eq!(joined.get(&2), Some(&('b', None)));
+    assert_eq!(joined.get(&3), Some(&('c', Some("three"))));
+}
+
+#[test]
+fn test_outer_join() {
+    let (left, right) = maps();
+    let joined: Vec<_> = left.outer_join(&right).collect();
+    assert_eq!(
+        joined,
+        [
+            (&1, Both(&'a', &"one")),
+            (&2, Left(&'b')),
+            (&3, Both(&'c', &"three")),
+            (&4, Left(&'d')),
+            (&5, Right(&"five")),
+            (&6, Right(&"six")),
+        ]
+    );
+
+    let joined = left.into_outer_join(right);
+    assert_eq!(
+        joined.keys().cloned().collect::<Vec<_>>(),
+        [1, 2, 3, 4, 5, 6]
+    );
+    assert_eq!(joined.get(&1), Some(&Both('a', "one")));
+    assert_eq!(joined.get(&5), Some(&Right("five")));
+}
+
+#[test]
+fn test_either_or_both() {
+    let both: linear_map::join::EitherOrBoth<i32, char> = Both(1, 'a');
+    assert_eq!(both.left(), Some(1));
+    assert_eq!(both.right(), Some('a'));
+    assert_eq!(both.both(), Some((1, 'a')));
+    assert_eq!(both.as_ref(), Both(&1, &'a'));
+    assert_eq!(Left::<i32, char>(1).right(), None);
+    assert_eq!(Right::<i32, char>('a').both(), None);
+}
+
+#[test]
+fn test_join_debug() {
+    let (left, right) = maps();
+    assert_eq!(
+        format!("{:?}", left.inner_join(&right)),
+        r#"[(1, 'a', "one"), (3, 'c', "three")]"#
+    );
+}
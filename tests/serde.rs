@@ -35,6 +35,49 @@ fn test_ser_de() {
     );
 }
 
+mod bag {
+    use linear_map::bag::LinearBag;
+    use serde_test::{assert_de_tokens, assert_tokens, Token};
+
+    #[test]
+    fn test_ser_de() {
+        let bag: LinearBag<_> = "abca".chars().collect();
+
+        assert_tokens(
+            &bag,
+            &[
+                Token::Map { len: Some(3) },
+                Token::Char('a'),
+                Token::U64(2),
+                Token::Char('b'),
+                Token::U64(1),
+                Token::Char('c'),
+                Token::U64(1),
+                Token::MapEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_de_skips_zero_counts() {
+        let bag: LinearBag<_> = "aab".chars().collect();
+
+        assert_de_tokens(
+            &bag,
+            &[
+                Token::Map { len: Some(3) },
+                Token::Char('a'),
+                Token::U64(2),
+                Token::Char('z'),
+                Token::U64(0),
+                Token::Char('b'),
+                Token::U64(1),
+                Token::MapEnd,
+            ],
+        );
+    }
+}
+
 mod set {
     use linear_map::set::LinearSet;
     use serde_test::{assert_tokens, Token};
@@ -64,3 +107,53 @@ mod set {
         );
     }
 }
+
+mod diff {
+    use linear_map::diff::Change::{Added, Modified, Removed};
+    use linear_map::diff::Patch;
+    use serde_test::{assert_tokens, Token};
+
+    #[test]
+    fn test_ser_de_empty() {
+        let patch = Patch::<char, u32>::new();
+        assert_tokens(&patch, &[Token::Seq { len: Some(0) }, Token::SeqEnd]);
+    }
+
+    #[test]
+    fn test_ser_de() {
+        let patch: Patch<_, _> = vec![Added('a', 1), Removed('b', 2), Modified('c', 3, 4)].into();
+
+        assert_tokens(
+            &patch,
+            &[
+                Token::Seq { len: Some(3) },
+                Token::TupleVariant {
+                    name: "Change",
+                    variant: "Added",
+                    len: 2,
+                },
+                Token::Char('a'),
+                Token::I32(1),
+                Token::TupleVariantEnd,
+                Token::TupleVariant {
+                    name: "Change",
+                    variant: "Removed",
+                    len: 2,
+                },
+                Token::Char('b'),
+                Token::I32(2),
+                Token::TupleVariantEnd,
+                Token::TupleVariant {
+                    name: "Change",
+                    variant: "Modified",
+                    len: 3,
+                },
+                Token::Char('c'),
+                Token::I32(3),
+                Token::I32(4),
+                Token::TupleVariantEnd,
+                Token::SeqEnd,
+            ],
+        );
+    }
+}
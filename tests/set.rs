@@ -1,6 +1,7 @@
 extern crate linear_map;
 
 use linear_map::set::LinearSet;
+use linear_map::DedupPolicy;
 
 #[test]
 fn test_disjoint() {
@@ -240,12 +241,12 @@ fn test_show() {
 #[test]
 fn test_trivial_drain() {
     let mut s = LinearSet::<i32>::new();
-    for _ in s.drain() {}
+    for _ in s.drain(..) {}
     assert!(s.is_empty());
     drop(s);
 
     let mut s = LinearSet::<i32>::new();
-    drop(s.drain());
+    drop(s.drain(..));
     assert!(s.is_empty());
 }
 
@@ -259,7 +260,7 @@ fn test_drain() {
 
         {
             let mut last_i = 0;
-            let mut d = s.drain();
+            let mut d = s.drain(..);
             for (i, x) in d.by_ref().take(50).enumerate() {
                 last_i = i;
                 assert!(x != 0);
@@ -267,6 +268,7 @@ fn test_drain() {
             assert_eq!(last_i, 49);
         }
 
+        #[allow(clippy::never_loop)]
         for _ in &s {
             panic!("s should be empty!");
         }
@@ -325,3 +327,464 @@ fn test_retain() {
     assert!(set.contains(&4));
     assert!(set.contains(&6));
 }
+
+#[test]
+fn test_extract_if() {
+    let mut set: LinearSet<isize> = (0..10).collect();
+    let mut extracted: Vec<_> = set.extract_if(|&k| k % 3 == 0).collect();
+    extracted.sort();
+    assert_eq!(extracted, [0, 3, 6, 9]);
+    assert_eq!(set.len(), 6);
+    assert!(set.iter().all(|k| k % 3 != 0));
+}
+
+#[test]
+fn test_partition() {
+    let set: LinearSet<isize> = (0..10).collect();
+    let (small, large) = set.partition(|&k| k < 4);
+    assert_eq!(small.len(), 4);
+    assert_eq!(large.len(), 6);
+    assert!(small.contains(&3));
+    assert!(large.contains(&4));
+}
+
+#[test]
+fn test_sort() {
+    let mut set: LinearSet<i32> = vec![3, -1, 2].into_iter().collect();
+    assert_eq!(set.sorted_iter().cloned().collect::<Vec<_>>(), [-1, 2, 3]);
+
+    set.sort_by_cached_key(|x| x.abs());
+    assert_eq!(set.iter().cloned().collect::<Vec<_>>(), [-1, 2, 3]);
+
+    set.sort_unstable_by_key(|x| -x);
+    assert_eq!(set.iter().cloned().collect::<Vec<_>>(), [3, 2, -1]);
+
+    set.sort();
+    set.reverse();
+    assert_eq!(set.iter().cloned().collect::<Vec<_>>(), [3, 2, -1]);
+}
+
+#[test]
+fn test_range_operations() {
+    let mut set: LinearSet<i32> = (0..10).collect();
+    assert_eq!(set.drain(..2).collect::<Vec<_>>(), [0, 1]);
+    let mut tail = set.split_off(4);
+    assert_eq!(Vec::from(tail.clone()), [6, 7, 8, 9]);
+    set.truncate(3);
+    assert_eq!(set.pop(), Some(4));
+    set.append(&mut tail);
+    assert_eq!(Vec::from(set), [2, 3, 6, 7, 8, 9]);
+    assert!(tail.is_empty());
+}
+
+#[test]
+fn test_from_vec_checked() {
+    assert_eq!(LinearSet::try_from_vec(vec![1, 2, 3]).unwrap().len(), 3);
+    let err = LinearSet::try_from_vec(vec![1, 2, 3, 3]).unwrap_err();
+    assert_eq!((err.first(), err.second()), (2, 3));
+
+    let set = LinearSet::from_vec_dedup(vec![1, 2, 1, 3, 2], DedupPolicy::LastWins);
+    assert_eq!(Vec::from(set), [1, 2, 3]);
+}
+
+#[test]
+fn test_insert_unique_unchecked() {
+    let mut set = LinearSet::new();
+    assert_eq!(set.insert_unique_unchecked(1), &1);
+    set.insert_unique_unchecked(2);
+    assert_eq!(Vec::from(set), [1, 2]);
+}
+
+#[test]
+fn test_hash_and_ord() {
+    use std::collections::{BTreeSet, HashSet};
+
+    let a: LinearSet<_> = vec![1, 2, 3].into_iter().collect();
+    let b: LinearSet<_> = vec![3, 2, 1].into_iter().collect();
+    let c: LinearSet<_> = vec![1, 2, 4].into_iter().collect();
+
+    let hashed: HashSet<_> = vec![a.clone(), b.clone(), c.clone()].into_iter().collect();
+    assert_eq!(hashed.len(), 2);
+
+    assert!(a < c);
+    assert_eq!(a.cmp(&b), std::cmp::Ordering::Equal);
+    let sorted: BTreeSet<_> = vec![c.clone(), b, a.clone()].into_iter().collect();
+    assert_eq!(sorted.into_iter().collect::<Vec<_>>(), [a, c]);
+}
+
+#[test]
+fn test_hashed_and_sorted_operations() {
+    let a: LinearSet<i32> = (0..50).filter(|x| x % 2 == 0).collect();
+    let b: LinearSet<i32> = (0..50).filter(|x| x % 3 == 0).collect();
+    let c: LinearSet<i32> = (0..50).filter(|x| x % 6 == 0).collect();
+    let d: LinearSet<i32> = (50..60).collect();
+
+    fn sorted<'a, I: Iterator<Item = &'a i32>>(iter: I) -> Vec<i32> {
+        let mut v: Vec<_> = iter.cloned().collect();
+        v.sort();
+        v
+    }
+
+    let intersection = sorted(a.intersection(&b));
+    assert_eq!(sorted(a.intersection_hashed(&b)), intersection);
+    assert_eq!(sorted(a.intersection_sorted(&b)), intersection);
+
+    let difference = sorted(a.difference(&b));
+    assert_eq!(sorted(a.difference_hashed(&b)), difference);
+    assert_eq!(sorted(a.difference_sorted(&b)), difference);
+
+    let symmetric_difference = sorted(a.symmetric_difference(&b));
+    assert_eq!(
+        sorted(a.symmetric_difference_hashed(&b)),
+        symmetric_difference
+    );
+    assert_eq!(
+        sorted(a.symmetric_difference_sorted(&b)),
+        symmetric_difference
+    );
+
+    assert!(c.is_subset_hashed(&a) && c.is_subset_sorted(&b));
+    assert!(!a.is_subset_hashed(&c) && !b.is_subset_sorted(&c));
+    assert!(a.is_disjoint_hashed(&d) && a.is_disjoint_sorted(&d));
+    assert!(!a.is_disjoint_hashed(&b) && !a.is_disjoint_sorted(&b));
+
+    let mut rev = a.clone();
+    rev.reverse();
+    assert!(a.eq_hashed(&rev) && a.eq_sorted(&rev));
+    assert!(!a.eq_hashed(&b) && !a.eq_sorted(&b));
+}
+
+#[test]
+fn test_iter_rev_and_debug() {
+    let a: LinearSet<i32> = (0..6).collect();
+    let b: LinearSet<i32> = (3..9).collect();
+
+    assert_eq!(
+        a.iter().rev().cloned().collect::<Vec<_>>(),
+        [5, 4, 3, 2, 1, 0]
+    );
+    assert_eq!(
+        a.clone().into_iter().rev().collect::<Vec<_>>(),
+        [5, 4, 3, 2, 1, 0]
+    );
+    assert_eq!(
+        a.intersection(&b).rev().cloned().collect::<Vec<_>>(),
+        [5, 4, 3]
+    );
+    assert_eq!(
+        a.difference(&b).rev().cloned().collect::<Vec<_>>(),
+        [2, 1, 0]
+    );
+
+    assert_eq!(format!("{:?}", a.iter()), "[0, 1, 2, 3, 4, 5]");
+    assert_eq!(format!("{:?}", a.intersection(&b)), "[3, 4, 5]");
+    assert_eq!(format!("{:?}", a.difference(&b)), "[0, 1, 2]");
+    assert_eq!(
+        format!("{:?}", a.symmetric_difference(&b)),
+        "[0, 1, 2, 6, 7, 8]"
+    );
+    assert_eq!(format!("{:?}", a.union(&b)), "[0, 1, 2, 3, 4, 5, 6, 7, 8]");
+
+    let mut into_iter = a.clone().into_iter();
+    into_iter.next_back();
+    assert_eq!(format!("{:?}", into_iter.clone()), "[0, 1, 2, 3, 4]");
+    assert_eq!(into_iter.len(), 5);
+
+    let mut c = a.clone();
+    assert_eq!(format!("{:?}", c.drain(..)), "[0, 1, 2, 3, 4, 5]");
+}
+
+#[test]
+fn test_get_take_replace() {
+    #[derive(Debug)]
+    struct Foo(&'static str, i32);
+
+    impl PartialEq for Foo {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+
+    impl Eq for Foo {}
+
+    let mut s = LinearSet::new();
+    assert_eq!(s.replace(Foo("a", 1)), None);
+    assert_eq!(s.len(), 1);
+    assert_eq!(s.replace(Foo("a", 2)), Some(Foo("a", 1)));
+    assert_eq!(s.len(), 1);
+    assert_eq!(s.get(&Foo("a", 0)).unwrap().1, 2);
+
+    assert_eq!(s.get_or_insert(Foo("a", 3)).1, 2);
+    assert_eq!(s.get_or_insert(Foo("b", 3)).1, 3);
+    assert_eq!(s.len(), 2);
+
+    assert_eq!(s.take(&Foo("a", 0)).map(|foo| foo.1), Some(2));
+    assert_eq!(s.take(&Foo("a", 0)), None);
+    assert_eq!(s.len(), 1);
+}
+
+#[test]
+fn test_get_or_insert_with() {
+    let mut s: LinearSet<String> = LinearSet::new();
+    assert_eq!(s.get_or_insert_with("a", str::to_owned), "a");
+    assert_eq!(s.get_or_insert_with("a", |_| unreachable!()), "a");
+    assert_eq!(s.len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "new value is not equal")]
+fn test_get_or_insert_with_not_equal() {
+    let mut s: LinearSet<String> = LinearSet::new();
+    s.insert("b".to_string());
+    s.get_or_insert_with("a", |_| "b".to_string());
+}
+
+#[test]
+fn test_entry() {
+    use linear_map::set::Entry;
+
+    let mut s: LinearSet<i32> = (0..3).collect();
+
+    match s.entry(1) {
+        Entry::Occupied(e) => {
+            assert_eq!(e.get(), &1);
+            assert_eq!(format!("{:?}", e), "OccupiedEntry(1)");
+            assert_eq!(e.remove(), 1);
+        }
+        Entry::Vacant(_) => unreachable!(),
+    }
+    assert!(!s.contains(&1));
+
+    match s.entry(5) {
+        Entry::Occupied(_) => unreachable!(),
+        Entry::Vacant(e) => {
+            assert_eq!(format!("{:?}", e), "VacantEntry(5)");
+            assert_eq!(e.into_value(), 5);
+        }
+    }
+    assert!(!s.contains(&5));
+
+    assert_eq!(s.entry(7).get(), &7);
+    assert_eq!(s.entry(7).insert().get(), &7);
+    assert!(s.contains(&7));
+    s.entry(8).or_insert();
+    s.entry(8).or_insert();
+    assert_eq!(s.len(), 4);
+    assert_eq!(format!("{:?}", s.entry(8)), "Entry(OccupiedEntry(8))");
+}
+
+#[test]
+fn test_assign_ops() {
+    let a: LinearSet<i32> = (0..6).collect();
+    let b: LinearSet<i32> = (3..9).collect();
+
+    let mut set = a.clone();
+    set |= &b;
+    assert_eq!(set, &a | &b);
+    let mut set = a.clone();
+    set |= b.clone();
+    assert_eq!(set, &a | &b);
+
+    let mut set = a.clone();
+    set &= &b;
+    assert_eq!(set, &a & &b);
+    let mut set = a.clone();
+    set &= b.clone();
+    assert_eq!(set, &a & &b);
+
+    let mut set = a.clone();
+    set ^= &b;
+    assert_eq!(set, &a ^ &b);
+    let mut set = a.clone();
+    set ^= b.clone();
+    assert_eq!(set, &a ^ &b);
+
+    let mut set = a.clone();
+    set -= &b;
+    assert_eq!(set, &a - &b);
+    let mut set = a.clone();
+    set -= b.clone();
+    assert_eq!(set, &a - &b);
+}
+
+#[test]
+fn test_named_in_place_ops() {
+    let a: LinearSet<i32> = (0..6).collect();
+    let b: LinearSet<i32> = (3..9).collect();
+
+    let mut set = a.clone();
+    set.union_with(&b);
+    assert!(set.iter().eq(a.union(&b)));
+
+    let mut set = a.clone();
+    set.intersect_with(&b);
+    assert!(set.iter().eq(a.intersection(&b)));
+
+    let mut set = a.clone();
+    set.difference_with(&b);
+    assert!(set.iter().eq(a.difference(&b)));
+
+    let mut set = a.clone();
+    set.symmetric_difference_with(&b);
+    assert!(set.iter().eq(a.symmetric_difference(&b)));
+}
+
+#[test]
+fn test_owned_ops() {
+    let a: LinearSet<i32> = (0..4).collect();
+    let b: LinearSet<i32> = (2..6).collect();
+    let c: LinearSet<i32> = (4..8).collect();
+
+    assert_eq!(
+        a.clone() | b.clone() | c.clone(),
+        (0..8).collect::<LinearSet<_>>()
+    );
+    assert_eq!(a.clone() | &b | &c, (0..8).collect::<LinearSet<_>>());
+    assert_eq!(
+        (a.clone() | b.clone()) & c.clone(),
+        (4..6).collect::<LinearSet<_>>()
+    );
+    assert_eq!(a.clone() & &b, (2..4).collect::<LinearSet<_>>());
+    assert_eq!(a.clone() ^ b.clone(), LinearSet::from(vec![0, 1, 4, 5]));
+    assert_eq!(a.clone() ^ &b, LinearSet::from(vec![0, 1, 4, 5]));
+    assert_eq!(a.clone() - b.clone() - &c, (0..2).collect::<LinearSet<_>>());
+}
+
+#[test]
+fn test_set_like() {
+    use linear_map::set::SortedSlice;
+    use std::collections::{BTreeSet, HashSet};
+
+    let a: LinearSet<i32> = (0..6).collect();
+    let hash: HashSet<i32> = (3..9).collect();
+    let btree: BTreeSet<i32> = (3..9).collect();
+    let slice: Vec<i32> = (3..9).collect();
+    let sorted = SortedSlice::new(&slice);
+
+    let expected = [&0, &1, &2];
+    assert_eq!(a.difference(&hash).collect::<Vec<_>>(), expected);
+    assert_eq!(a.difference(&btree).collect::<Vec<_>>(), expected);
+    assert_eq!(a.difference(&sorted).collect::<Vec<_>>(), expected);
+
+    let expected = [&3, &4, &5];
+    assert_eq!(a.intersection(&hash).collect::<Vec<_>>(), expected);
+    assert_eq!(a.intersection(&btree).collect::<Vec<_>>(), expected);
+    assert_eq!(a.intersection(&sorted).rev().count(), 3);
+
+    let sub: LinearSet<i32> = (4..6).collect();
+    assert!(sub.is_subset(&hash) && sub.is_subset(&btree) && sub.is_subset(&sorted));
+    assert!(!a.is_subset(&hash) && !a.is_subset(&btree) && !a.is_subset(&sorted));
+    let low: LinearSet<i32> = (0..3).collect();
+    assert!(low.is_disjoint(&hash) && low.is_disjoint(&btree) && low.is_disjoint(&sorted));
+    assert!(!a.is_disjoint(&hash));
+
+    let mut set = a.clone();
+    set.intersect_with(&hash);
+    assert_eq!(set, (3..6).collect::<LinearSet<_>>());
+    set.difference_with(&sorted);
+    assert!(set.is_empty());
+}
+
+#[test]
+fn test_set_like_union_with() {
+    use std::collections::HashSet;
+
+    let hash: HashSet<i32> = (3..9).collect();
+    let mut set: LinearSet<i32> = (0..6).collect();
+
+    set.union_with(&hash);
+    assert_eq!(set, (0..9).collect::<LinearSet<_>>());
+
+    let mut set: LinearSet<i32> = (0..6).collect();
+    set.symmetric_difference_with(&hash);
+    assert_eq!(
+        set,
+        [0, 1, 2, 6, 7, 8].iter().cloned().collect::<LinearSet<_>>()
+    );
+    set.symmetric_difference_with(&hash);
+    assert_eq!(set, (0..6).collect::<LinearSet<_>>());
+}
+
+#[test]
+fn test_eq_std_sets() {
+    use std::collections::{BTreeSet, HashSet};
+
+    let a: LinearSet<i32> = (0..6).rev().collect();
+    let hash: HashSet<i32> = (0..6).collect();
+    let btree: BTreeSet<i32> = (0..6).collect();
+    assert_eq!(a, hash);
+    assert_eq!(hash, a);
+    assert_eq!(a, btree);
+    assert_eq!(btree, a);
+
+    let b: LinearSet<i32> = (0..5).collect();
+    assert_ne!(b, hash);
+    assert_ne!(hash, b);
+    assert_ne!(b, btree);
+    assert_ne!(btree, b);
+}
+
+#[test]
+fn test_std_set_conversions() {
+    use std::collections::{BTreeSet, HashSet};
+
+    let set = LinearSet::from([3, 1, 3, 2]);
+    assert_eq!(Vec::from(set.clone()), [3, 1, 2]);
+
+    let hash_set: HashSet<_> = set.clone().into();
+    assert_eq!(hash_set, set);
+    assert_eq!(LinearSet::from(hash_set), set);
+
+    let btree_set: BTreeSet<_> = set.clone().into();
+    assert_eq!(btree_set, set);
+    assert_eq!(Vec::from(LinearSet::from(btree_set)), [1, 2, 3]);
+}
+
+#[test]
+fn test_set_like_mut() {
+    use linear_map::set::{SetLike, SetLikeMut, SortedSlice};
+    use std::collections::{BTreeSet, HashSet};
+
+    fn exercise<S: SetLikeMut<String, str> + Default>() -> S {
+        let mut set = S::default();
+        assert!(set.is_empty());
+        assert!(set.insert("a".to_string()));
+        assert!(!set.insert("a".to_string()));
+        assert_eq!(set.get_or_insert_with("b", |s| s.to_string()), "b");
+        assert!(set.contains(&"b".to_string()));
+        assert!(set.remove("a"));
+        assert!(!set.remove("a"));
+        assert_eq!(set.len(), 1);
+        assert_eq!(set.iter().collect::<Vec<_>>(), ["b"]);
+        set
+    }
+
+    let linear = exercise::<LinearSet<_>>();
+    assert_eq!(linear, exercise::<HashSet<_>>());
+    assert_eq!(linear, exercise::<BTreeSet<_>>());
+
+    let slice = [1, 2, 3];
+    let sorted = SortedSlice::new(&slice);
+    assert_eq!(SetLike::len(&sorted), 3);
+    assert_eq!(SetLike::iter(&sorted).sum::<i32>(), 6);
+}
+
+#[test]
+fn test_set_like_mut_get_or_insert_with_not_equal() {
+    use linear_map::set::SetLikeMut;
+    use std::collections::{BTreeSet, HashSet};
+    use std::panic::{self, AssertUnwindSafe};
+
+    fn exercise<S: SetLikeMut<i32> + Default>() {
+        let mut set = S::default();
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            set.get_or_insert_with(&1, |_| 2);
+        }));
+        assert!(result.is_err());
+        assert!(set.is_empty());
+    }
+
+    exercise::<LinearSet<_>>();
+    exercise::<HashSet<_>>();
+    exercise::<BTreeSet<_>>();
+}
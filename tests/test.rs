@@ -2,7 +2,7 @@
 extern crate linear_map;
 
 use linear_map::Entry::{Occupied, Vacant};
-use linear_map::LinearMap;
+use linear_map::{DedupPolicy, LinearMap};
 
 const TEST_CAPACITY: usize = 10;
 
@@ -274,3 +274,688 @@ fn test_retain() {
     assert_eq!(map[&4], 40);
     assert_eq!(map[&6], 60);
 }
+
+#[test]
+fn test_extract_if() {
+    let mut map: LinearMap<isize, isize> = (0..100).map(|x| (x, x * 10)).collect();
+    let mut extracted: Vec<_> = map.extract_if(|&k, _| k % 2 == 0).collect();
+    extracted.sort();
+    assert_eq!(extracted.len(), 50);
+    assert_eq!(extracted[1], (2, 20));
+    assert_eq!(map.len(), 50);
+    assert!(map.keys().all(|k| k % 2 == 1));
+
+    // Pairs not yet visited stay in the map.
+    let mut map: LinearMap<isize, isize> = (0..10).map(|x| (x, x)).collect();
+    assert_eq!(map.extract_if(|_, _| true).take(3).count(), 3);
+    assert_eq!(map.len(), 7);
+}
+
+#[test]
+fn test_partition() {
+    let map: LinearMap<isize, isize> = (0..100).map(|x| (x, x * 10)).collect();
+    let (evens, odds) = map.partition(|&k, _| k % 2 == 0);
+    assert_eq!(evens.len(), 50);
+    assert_eq!(odds.len(), 50);
+    assert_eq!(evens[&4], 40);
+    assert_eq!(odds[&5], 50);
+    assert!(odds.capacity() >= 100);
+}
+
+#[test]
+fn test_sort() {
+    let mut map: LinearMap<i32, i32> = vec![(3, 30), (1, 10), (2, 20)].into();
+    let sorted: Vec<_> = map.sorted_iter().map(|(&k, _)| k).collect();
+    assert_eq!(sorted, [1, 2, 3]);
+    assert_eq!(map.keys().cloned().collect::<Vec<_>>(), [3, 1, 2]);
+
+    map.sort_keys();
+    assert_eq!(Vec::from(map.clone()), [(1, 10), (2, 20), (3, 30)]);
+
+    map.reverse();
+    assert_eq!(Vec::from(map.clone()), [(3, 30), (2, 20), (1, 10)]);
+
+    map.sort_by(|_, v1, _, v2| v1.cmp(v2));
+    assert_eq!(Vec::from(map.clone()), [(1, 10), (2, 20), (3, 30)]);
+
+    map.sort_unstable_by_key(|&k, _| -k);
+    assert_eq!(Vec::from(map.clone()), [(3, 30), (2, 20), (1, 10)]);
+
+    map.sort_by_cached_key(|_, v| v.to_string());
+    assert_eq!(Vec::from(map), [(1, 10), (2, 20), (3, 30)]);
+}
+
+#[test]
+fn test_slice() {
+    let mut map: LinearMap<i32, i32> = vec![(1, 10), (2, 20), (3, 30), (4, 40)].into();
+    {
+        let slice = map.as_slice();
+        assert_eq!(slice.len(), 4);
+        assert_eq!(slice.get(&3), Some(&30));
+        assert_eq!(slice.get(&5), None);
+        assert_eq!(slice.first(), Some((&1, &10)));
+        assert_eq!(slice.last(), Some((&4, &40)));
+        assert_eq!(slice.get_index(1), Some((&2, &20)));
+
+        let middle = &slice[1..3];
+        assert_eq!(middle.keys().cloned().collect::<Vec<_>>(), [2, 3]);
+        assert!(middle.get_range(1..3).is_none());
+        assert_eq!(slice.get_range(2..).unwrap().len(), 2);
+        assert!(slice[..0].is_empty());
+        assert_eq!(format!("{:?}", &slice[..=1]), "{1: 10, 2: 20}");
+    }
+    {
+        let slice = map.as_mut_slice();
+        *slice.get_mut(&1).unwrap() += 1;
+        *slice.last_mut().unwrap().1 += 1;
+        for (_, v) in &mut slice[1..3] {
+            *v += 1;
+        }
+    }
+    assert_eq!(map.values().cloned().collect::<Vec<_>>(), [11, 21, 31, 41]);
+
+    let mut iter = map.iter();
+    iter.next();
+    assert_eq!(iter.as_slice().len(), 3);
+    assert_eq!(iter.as_slice().first(), Some((&2, &21)));
+
+    let mut into_iter = map.into_iter();
+    into_iter.next_back();
+    assert_eq!(into_iter.as_slice().last(), Some((&3, &31)));
+}
+
+#[test]
+fn test_cursor() {
+    let map: LinearMap<i32, i32> = vec![(1, 10), (2, 20), (3, 30)].into();
+    let mut cursor = map.cursor();
+    cursor.move_prev();
+    assert_eq!(cursor.current(), Some((&1, &10)));
+    cursor.move_next();
+    assert_eq!(cursor.key(), Some(&2));
+    assert_eq!(cursor.value(), Some(&20));
+    cursor.move_next();
+    cursor.move_next();
+    cursor.move_next();
+    assert_eq!(cursor.index(), 3);
+    assert_eq!(cursor.current(), None);
+}
+
+#[test]
+fn test_cursor_mut() {
+    let mut map: LinearMap<i32, i32> = (0..10).map(|x| (x, x)).collect();
+    {
+        let mut cursor = map.cursor_mut();
+        while let Some(&k) = cursor.key() {
+            if k % 3 == 0 {
+                assert_eq!(cursor.remove_current(), Some((k, k)));
+            } else {
+                *cursor.value_mut().unwrap() *= 10;
+                cursor.move_next();
+            }
+        }
+        assert_eq!(cursor.remove_current(), None);
+    }
+    assert_eq!(map.len(), 6);
+    assert!(map.iter().all(|(&k, &v)| k % 3 != 0 && v == k * 10));
+
+    let mut map: LinearMap<i32, i32> = (0..5).map(|x| (x, x)).collect();
+    {
+        let mut cursor = map.cursor_mut();
+        cursor.move_next();
+        assert_eq!(cursor.shift_remove_current(), Some((1, 1)));
+        assert_eq!(cursor.key(), Some(&2));
+        assert_eq!(cursor.insert_before(10, 100), Ok(()));
+        assert_eq!(cursor.insert_before(3, 300), Err((3, 300)));
+        assert_eq!(cursor.key(), Some(&2));
+        assert_eq!(cursor.as_cursor().index(), 2);
+        assert_eq!(cursor.as_entry().unwrap().insert(200), 2);
+    }
+    assert_eq!(
+        Vec::from(map),
+        [(0, 0), (10, 100), (2, 200), (3, 3), (4, 4)]
+    );
+}
+
+#[test]
+fn test_drain() {
+    let mut map: LinearMap<i32, i32> = (0..10).map(|x| (x, x)).collect();
+    let drained: Vec<_> = map.drain(2..5).collect();
+    assert_eq!(drained, [(2, 2), (3, 3), (4, 4)]);
+    assert_eq!(map.len(), 7);
+    assert_eq!(
+        map.keys().cloned().collect::<Vec<_>>(),
+        [0, 1, 5, 6, 7, 8, 9]
+    );
+
+    drop(map.drain(5..));
+    assert_eq!(map.keys().cloned().collect::<Vec<_>>(), [0, 1, 5, 6, 7]);
+}
+
+#[test]
+fn test_split_off_truncate_pop() {
+    let mut map: LinearMap<i32, i32> = (0..10).map(|x| (x, x)).collect();
+    let tail = map.split_off(6);
+    assert_eq!(map.len(), 6);
+    assert_eq!(tail.keys().cloned().collect::<Vec<_>>(), [6, 7, 8, 9]);
+
+    map.truncate(3);
+    assert_eq!(map.len(), 3);
+    map.truncate(5);
+    assert_eq!(map.len(), 3);
+
+    assert_eq!(map.pop(), Some((2, 2)));
+    assert_eq!(map.pop(), Some((1, 1)));
+    assert_eq!(map.pop(), Some((0, 0)));
+    assert_eq!(map.pop(), None);
+}
+
+#[test]
+fn test_append() {
+    let mut a: LinearMap<i32, &str> = vec![(1, "a"), (2, "b")].into();
+    let mut b: LinearMap<i32, &str> = vec![(2, "B"), (3, "C")].into();
+    let capacity = b.capacity();
+    a.append(&mut b);
+    assert_eq!(Vec::from(a), [(1, "a"), (2, "B"), (3, "C")]);
+    assert!(b.is_empty());
+    assert_eq!(b.capacity(), capacity);
+}
+
+#[test]
+fn test_update_upsert_alter() {
+    let mut map: LinearMap<&str, i32> = LinearMap::new();
+    assert_eq!(map.update("a", |v| *v += 1), None);
+
+    for word in "a b a c a b".split(' ') {
+        map.upsert(word, || 1, |count| *count += 1);
+    }
+    assert_eq!(map["a"], 3);
+    assert_eq!(map["b"], 2);
+    assert_eq!(map["c"], 1);
+
+    assert_eq!(map.update("a", |v| *v *= 10), Some(&mut 30));
+
+    // Decrement, removing the key once the count drops to zero.
+    let decrement = |count: Option<i32>| count.map(|c| c - 1).filter(|&c| c > 0);
+    assert_eq!(map.alter("c", decrement), None);
+    assert!(!map.contains_key("c"));
+    assert_eq!(map.alter("b", decrement), Some(&mut 1));
+    assert_eq!(map.alter("d", decrement), None);
+    assert!(!map.contains_key("d"));
+    assert_eq!(map.alter("d", |_| Some(4)), Some(&mut 4));
+    assert_eq!(Vec::from(map), [("a", 30), ("b", 1), ("d", 4)]);
+}
+
+#[test]
+fn test_alter_panic() {
+    use std::panic::{self, AssertUnwindSafe};
+
+    let mut map = linear_map! {
+        "a".to_string() => "x".to_string(),
+        "b".to_string() => "y".to_string(),
+        "c".to_string() => "z".to_string(),
+    };
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        map.alter("a".to_string(), |_| panic!("boom"));
+    }));
+    assert!(result.is_err());
+    assert_eq!(
+        Vec::from(map),
+        [
+            ("b".to_string(), "y".to_string()),
+            ("c".to_string(), "z".to_string())
+        ]
+    );
+}
+
+#[test]
+fn test_entry_and_modify() {
+    let mut map = LinearMap::new();
+    map.entry(1).and_modify(|v| *v += 1).or_insert(10);
+    assert_eq!(map[&1], 10);
+    map.entry(1).and_modify(|v| *v += 1).or_insert(10);
+    assert_eq!(map[&1], 11);
+}
+
+#[test]
+fn test_insert_many() {
+    let batch = vec![(2, 'B'), (4, 'd'), (1, 'A'), (4, 'D'), (5, 'e')];
+    let expected_map = vec![(1, 'A'), (2, 'B'), (3, 'c'), (4, 'D'), (5, 'e')];
+    let expected_displaced = vec![(2, 'b'), (1, 'a'), (4, 'd')];
+
+    let mut map: LinearMap<i32, char> = vec![(1, 'a'), (2, 'b'), (3, 'c')].into();
+    assert_eq!(map.insert_many(batch.clone()), expected_displaced);
+    assert_eq!(Vec::from(map), expected_map);
+
+    let mut map: LinearMap<i32, char> = vec![(1, 'a'), (2, 'b'), (3, 'c')].into();
+    assert_eq!(map.insert_many_hashed(batch.clone()), expected_displaced);
+    assert_eq!(Vec::from(map), expected_map);
+
+    let mut map: LinearMap<i32, char> = vec![(1, 'a'), (2, 'b'), (3, 'c')].into();
+    assert_eq!(map.insert_many_sorted(batch), expected_displaced);
+    assert_eq!(Vec::from(map), expected_map);
+}
+
+#[test]
+fn test_remove_all() {
+    let keys = [2, 4, 6, 100, 4];
+
+    let mut map: LinearMap<i32, i32> = (0..10).map(|x| (x, x)).collect();
+    assert_eq!(map.remove_all(&keys), 3);
+    assert_eq!(map.len(), 7);
+    assert!(!map.contains_key(&4));
+
+    let mut map: LinearMap<i32, i32> = (0..10).map(|x| (x, x)).collect();
+    assert_eq!(map.remove_all_hashed(&keys), 3);
+    assert_eq!(map.len(), 7);
+    assert!(!map.contains_key(&6));
+
+    let mut map: LinearMap<String, i32> = (0..10).map(|x| (x.to_string(), x)).collect();
+    assert_eq!(map.remove_all_sorted(vec!["2", "4", "x"]), 2);
+    assert_eq!(map.len(), 8);
+    assert!(!map.contains_key("2"));
+}
+
+#[test]
+fn test_retain_keys() {
+    let mut map: LinearMap<i32, i32> = (0..10).map(|x| (x, x)).collect();
+    let keys = vec![1, 3, 5, 42].into_iter().collect();
+    map.retain_keys(&keys);
+    assert_eq!(map.len(), 3);
+    assert_eq!(map[&5], 5);
+}
+
+#[test]
+fn test_from_vec_checked() {
+    let map = LinearMap::try_from_vec(vec![(1, 'a'), (2, 'b')]).unwrap();
+    assert_eq!(map.len(), 2);
+
+    let err = LinearMap::try_from_vec(vec![(1, 'a'), (2, 'b'), (3, 'c'), (2, 'B')]).unwrap_err();
+    assert_eq!((err.first(), err.second()), (1, 3));
+    assert_eq!(err.to_string(), "duplicate keys at positions 1 and 3");
+
+    let pairs = vec![(1, 'a'), (2, 'b'), (1, 'A'), (3, 'c'), (2, 'B')];
+    let first = LinearMap::from_vec_dedup(pairs.clone(), DedupPolicy::FirstWins);
+    assert_eq!(Vec::from(first), [(1, 'a'), (2, 'b'), (3, 'c')]);
+    let last = LinearMap::from_vec_dedup(pairs, DedupPolicy::LastWins);
+    assert_eq!(Vec::from(last), [(1, 'A'), (2, 'B'), (3, 'c')]);
+
+    let unchecked = LinearMap::from_vec_unchecked(vec![(1, 'a'), (2, 'b')]);
+    assert_eq!(unchecked.len(), 2);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "vector contains duplicate keys")]
+fn test_from_vec_duplicates() {
+    let _: LinearMap<_, _> = vec![(1, 'a'), (1, 'b')].into();
+}
+
+#[test]
+fn test_insert_unique_unchecked() {
+    let mut map = LinearMap::new();
+    *map.insert_unique_unchecked(1, 10) += 1;
+    map.extend_unique_unchecked((2..5).map(|x| (x, x * 10)));
+    assert_eq!(Vec::from(map), [(1, 11), (2, 20), (3, 30), (4, 40)]);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "map already contains the key")]
+fn test_insert_unique_unchecked_duplicate() {
+    let mut map = LinearMap::new();
+    map.extend_unique_unchecked(vec![(1, 10), (1, 11)]);
+}
+
+#[test]
+fn test_hash() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::HashSet;
+    use std::hash::{Hash, Hasher};
+
+    fn hash<T: Hash>(t: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        t.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    let m1: LinearMap<_, _> = vec![('a', 1), ('b', 2), ('c', 3)].into();
+    let m2: LinearMap<_, _> = vec![('c', 3), ('a', 1), ('b', 2)].into();
+    let m3: LinearMap<_, _> = vec![('a', 1), ('b', 2), ('c', 4)].into();
+    assert_eq!(hash(&m1), hash(&m2));
+    assert!(hash(&m1) != hash(&m3));
+
+    let maps: HashSet<_> = vec![m1, m2, m3].into_iter().collect();
+    assert_eq!(maps.len(), 2);
+}
+
+#[test]
+fn test_ord() {
+    use std::cmp::Ordering;
+
+    let m1: LinearMap<_, _> = vec![('b', 2), ('a', 1)].into();
+    let m2: LinearMap<_, _> = vec![('a', 1), ('b', 2)].into();
+    let m3: LinearMap<_, _> = vec![('a', 1), ('c', 0)].into();
+    let m4: LinearMap<_, _> = vec![('a', 1)].into();
+    assert_eq!(m1.cmp(&m2), Ordering::Equal);
+    assert!(m1 < m3);
+    assert!(m4 < m1);
+
+    let m5: LinearMap<_, _> = vec![('a', 1.0)].into();
+    let m6: LinearMap<_, _> = vec![('a', f64::NAN)].into();
+    assert_eq!(m5.partial_cmp(&m6), None);
+
+    let mut outer = LinearMap::new();
+    outer.insert(m3.clone(), "m3");
+    outer.insert(m1.clone(), "m1");
+    outer.sort_keys();
+    assert_eq!(outer.values().cloned().collect::<Vec<_>>(), ["m1", "m3"]);
+}
+
+#[test]
+fn test_eq_hashed_and_sorted() {
+    let m1: LinearMap<_, _> = (0..20).map(|x| (x, x * 2)).collect();
+    let m2: LinearMap<_, _> = (0..20).rev().map(|x| (x, x * 2)).collect();
+    let mut m3 = m2.clone();
+    m3.insert(7, 0);
+    assert!(m1.eq_hashed(&m2) && m1.eq_sorted(&m2));
+    assert!(!m1.eq_hashed(&m3) && !m1.eq_sorted(&m3));
+    m3.remove(&7);
+    assert!(!m1.eq_hashed(&m3) && !m1.eq_sorted(&m3));
+}
+
+#[test]
+fn test_iter_rev_and_debug() {
+    let mut map: LinearMap<i32, char> = vec![(1, 'a'), (2, 'b'), (3, 'c')].into_iter().collect();
+
+    let keys: Vec<_> = map.keys().rev().cloned().collect();
+    assert_eq!(keys, [3, 2, 1]);
+    let pairs: Vec<_> = map.clone().into_iter().rev().collect();
+    assert_eq!(pairs, [(3, 'c'), (2, 'b'), (1, 'a')]);
+
+    let mut iter = map.iter();
+    assert_eq!(iter.next(), Some((&1, &'a')));
+    assert_eq!(iter.next_back(), Some((&3, &'c')));
+    assert_eq!(format!("{:?}", iter), "[(2, 'b')]");
+    assert_eq!(format!("{:?}", map.keys()), "[1, 2, 3]");
+    assert_eq!(format!("{:?}", map.values()), "['a', 'b', 'c']");
+
+    let mut into_iter = map.clone().into_iter();
+    into_iter.next();
+    let cloned = into_iter.clone();
+    assert_eq!(format!("{:?}", cloned), "[(2, 'b'), (3, 'c')]");
+    assert_eq!(into_iter.collect::<Vec<_>>(), cloned.collect::<Vec<_>>());
+
+    assert_eq!(
+        format!("{:?}", map.entry(2)),
+        "Entry(OccupiedEntry { key: 2, value: 'b' })"
+    );
+    assert_eq!(format!("{:?}", map.entry(4)), "Entry(VacantEntry(4))");
+    assert_eq!(
+        format!("{:?}", map.drain(..)),
+        "[(1, 'a'), (2, 'b'), (3, 'c')]"
+    );
+}
+
+#[test]
+fn test_values_mut_into_keys_into_values() {
+    let mut map: LinearMap<i32, String> = LinearMap::new();
+    map.insert(1, "a".to_string());
+    map.insert(2, "b".to_string());
+    map.insert(3, "c".to_string());
+
+    for v in map.values_mut() {
+        v.push('!');
+    }
+    assert_eq!(map.values_mut().len(), 3);
+    assert_eq!(map.values_mut().next_back().map(|v| v.as_str()), Some("c!"));
+
+    let mut keys = map.clone().into_keys();
+    assert_eq!(keys.next(), Some(1));
+    assert_eq!(format!("{:?}", keys.clone()), "[2, 3]");
+    assert_eq!(keys.rev().collect::<Vec<_>>(), [3, 2]);
+
+    let mut values = map.into_values();
+    assert_eq!(values.len(), 3);
+    assert_eq!(values.next(), Some("a!".to_string()));
+    assert_eq!(format!("{:?}", values), r#"["b!", "c!"]"#);
+    assert_eq!(values.clone().collect::<Vec<_>>(), ["b!", "c!"]);
+    assert_eq!(values.collect::<Vec<_>>(), ["b!", "c!"]);
+}
+
+#[test]
+fn test_get_key_value_and_remove_entry() {
+    let mut map = linear_map! { "a".to_string() => 1, "b".to_string() => 2 };
+    assert_eq!(map.get_key_value("a"), Some((&"a".to_string(), &1)));
+    assert_eq!(map.get_key_value("c"), None);
+    assert_eq!(map.remove_entry("a"), Some(("a".to_string(), 1)));
+    assert_eq!(map.remove_entry("a"), None);
+
+    match map.entry("b".to_string()) {
+        Occupied(e) => assert_eq!(e.remove_entry(), ("b".to_string(), 2)),
+        Vacant(_) => unreachable!(),
+    }
+    match map.entry("c".to_string()) {
+        Occupied(_) => unreachable!(),
+        Vacant(e) => assert_eq!(e.into_key(), "c"),
+    }
+    assert!(map.is_empty());
+
+    match map.entry("d".to_string()) {
+        Occupied(_) => unreachable!(),
+        Vacant(e) => {
+            let mut e = e.insert_entry(4);
+            *e.get_mut() += 1;
+        }
+    }
+    assert_eq!(map.get("d"), Some(&5));
+}
+
+#[test]
+fn test_key_set() {
+    use linear_map::set::LinearSet;
+
+    let a: LinearMap<i32, char> = vec![(1, 'a'), (2, 'b'), (3, 'c')].into_iter().collect();
+    let b: LinearMap<i32, bool> = vec![(2, true), (3, false), (4, true)].into_iter().collect();
+    let set: LinearSet<i32> = (0..4).collect();
+
+    let keys = a.key_set();
+    assert_eq!(keys.len(), 3);
+    assert!(keys.contains(&2) && !keys.contains(&4));
+    assert!(keys.is_subset(&set) && !keys.is_subset(&b.key_set()));
+    assert!(set.is_superset(&a.keys().cloned().collect()) && !set.is_subset(&keys));
+    assert!(!keys.is_disjoint(&b.key_set()));
+    assert!(b.key_set().is_disjoint(&LinearSet::from(vec![0, 1])));
+
+    let intersection: Vec<_> = keys.intersection(&b.key_set()).collect();
+    assert_eq!(intersection, [&2, &3]);
+    let difference: Vec<_> = keys.difference(&b.key_set()).collect();
+    assert_eq!(difference, [&1]);
+    let union: Vec<_> = keys.union(b.key_set()).collect();
+    assert_eq!(union, [&1, &2, &3, &4]);
+    let union: Vec<_> = keys.union(&set).collect();
+    assert_eq!(union, [&1, &2, &3, &0]);
+
+    let difference: Vec<_> = set.difference(&keys).collect();
+    assert_eq!(difference, [&0]);
+    assert_eq!(keys.into_iter().count(), 3);
+    assert_eq!(format!("{:?}", keys), "{1, 2, 3}");
+}
+
+#[test]
+fn test_merge_with_panic() {
+    use std::panic::{self, AssertUnwindSafe};
+
+    let mut map = linear_map! { 1 => "a".to_string(), 2 => "b".to_string(), 3 => "c".to_string() };
+    let other = linear_map! { 2 => "x".to_string() };
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        map.merge_with(other, |_, _, _| panic!("boom"));
+    }));
+    assert!(result.is_err());
+    assert_eq!(Vec::from(map), [(1, "a".to_string()), (3, "c".to_string())]);
+}
+
+#[test]
+fn test_merge_with() {
+    let mut map = linear_map! { 1 => 10, 2 => 20, 3 => 30 };
+    let other = linear_map! { 4 => 40, 2 => 2, 1 => 1 };
+
+    let mut borrowed = map.clone();
+    borrowed.merge_from(&other, |_, a, b| a + b);
+
+    map.merge_with(other, |&k, a, b| {
+        assert!(k == 1 || k == 2);
+        a + b
+    });
+    assert_eq!(
+        map.iter().collect::<Vec<_>>(),
+        [(&1, &11), (&2, &22), (&3, &30), (&4, &40)]
+    );
+    assert_eq!(borrowed, map);
+}
+
+#[test]
+fn test_deep_merge() {
+    let mut base = linear_map! {
+        "server" => linear_map! { "host" => "localhost", "port" => "80" },
+        "log" => linear_map! { "level" => "info" },
+    };
+    let overrides = linear_map! {
+        "server" => linear_map! { "port" => "8080" },
+        "cache" => linear_map! { "size" => "64" },
+    };
+
+    base.deep_merge(overrides);
+    assert_eq!(base.len(), 3);
+    assert_eq!(base["server"]["host"], "localhost");
+    assert_eq!(base["server"]["port"], "8080");
+    assert_eq!(base["log"]["level"], "info");
+    assert_eq!(base["cache"]["size"], "64");
+}
+
+#[test]
+fn test_map_values() {
+    let map = linear_map! { 1 => "one", 2 => "two", 3 => "three" };
+
+    let lengths = map.clone().map_values(|_, v| v.len());
+    assert_eq!(
+        lengths.iter().collect::<Vec<_>>(),
+        [(&1, &3), (&2, &3), (&3, &5)]
+    );
+
+    let odd = map.filter_map_values(|&k, v| {
+        if k % 2 == 1 {
+            Some(v.to_uppercase())
+        } else {
+            None
+        }
+    });
+    assert_eq!(odd.keys().cloned().collect::<Vec<_>>(), [1, 3]);
+    assert_eq!(odd[&3], "THREE");
+}
+
+#[test]
+fn test_map_keys() {
+    let map = linear_map! { 1 => 'a', 2 => 'b', 3 => 'c', 4 => 'd' };
+
+    let first = map.clone().map_keys(|k| k % 2, DedupPolicy::FirstWins);
+    assert_eq!(first.iter().collect::<Vec<_>>(), [(&1, &'a'), (&0, &'b')]);
+
+    let last = map.map_keys(|k| k % 2, DedupPolicy::LastWins);
+    assert_eq!(last.iter().collect::<Vec<_>>(), [(&1, &'c'), (&0, &'d')]);
+}
+
+#[test]
+fn test_invert() {
+    let map = linear_map! { 'a' => 1, 'b' => 2, 'c' => 1 };
+
+    let first = map.clone().invert(DedupPolicy::FirstWins);
+    assert_eq!(first.iter().collect::<Vec<_>>(), [(&1, &'a'), (&2, &'b')]);
+
+    let last = map.invert(DedupPolicy::LastWins);
+    assert_eq!(last.iter().collect::<Vec<_>>(), [(&1, &'c'), (&2, &'b')]);
+}
+
+#[test]
+fn test_from_array() {
+    let map = LinearMap::from([(1, 'a'), (2, 'b'), (1, 'c')]);
+    assert_eq!(map.iter().collect::<Vec<_>>(), [(&1, &'c'), (&2, &'b')]);
+}
+
+#[test]
+fn test_std_map_conversions() {
+    use std::collections::{BTreeMap, HashMap};
+
+    let map = linear_map! { 3 => 'c', 1 => 'a', 2 => 'b' };
+
+    let hash_map: HashMap<_, _> = map.clone().into();
+    assert_eq!(hash_map, map);
+    assert_eq!(map, hash_map);
+    assert_eq!(LinearMap::from(hash_map), map);
+
+    let btree_map: BTreeMap<_, _> = map.clone().into();
+    assert_eq!(btree_map, map);
+    assert_eq!(map, btree_map);
+    assert_eq!(
+        LinearMap::from(btree_map).iter().collect::<Vec<_>>(),
+        [(&1, &'a'), (&2, &'b'), (&3, &'c')]
+    );
+
+    let mut other: HashMap<_, _> = map.clone().into();
+    other.insert(3, 'z');
+    assert_ne!(map, other);
+    other.remove(&3);
+    assert_ne!(map, other);
+}
+
+#[test]
+fn test_map_like() {
+    use linear_map::MapLike;
+    use std::collections::{BTreeMap, HashMap};
+
+    fn exercise<M: MapLike<i32, char> + Default>() -> M {
+        let mut map = M::default();
+        assert!(map.is_empty());
+        assert_eq!(map.insert(1, 'a'), None);
+        assert_eq!(map.insert(2, 'b'), None);
+        assert_eq!(map.insert(1, 'c'), Some('a'));
+        assert_eq!(*map.get_or_insert_with(3, || 'd'), 'd');
+        assert_eq!(*map.get_or_insert_with(3, || 'z'), 'd');
+        *map.get_mut(&2).unwrap() = 'e';
+        assert_eq!(map.get(&2), Some(&'e'));
+        assert_eq!(map.remove(&3), Some('d'));
+        assert!(!map.contains_key(&3));
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.iter().count(), 2);
+        map
+    }
+
+    let linear = exercise::<LinearMap<_, _>>();
+    assert_eq!(linear, exercise::<HashMap<_, _>>());
+    assert_eq!(linear, exercise::<BTreeMap<_, _>>());
+
+    fn borrowed<M: MapLike<String, i32, str> + Default>() {
+        let mut map = M::default();
+        map.insert("a".to_string(), 1);
+        assert!(map.contains_key("a"));
+        *map.get_mut("a").unwrap() += 1;
+        assert_eq!(map.get("a"), Some(&2));
+        assert_eq!(map.remove("a"), Some(2));
+    }
+
+    borrowed::<LinearMap<_, _>>();
+    borrowed::<HashMap<_, _>>();
+    borrowed::<BTreeMap<_, _>>();
+
+    // A LinearMap only needs its keys to be `Eq`.
+    #[derive(PartialEq, Eq)]
+    struct Key(i32);
+
+    fn first_value<M: MapLike<Key, char>>(map: &M) -> Option<char> {
+        map.iter().next().map(|(_, &v)| v)
+    }
+
+    let mut map = LinearMap::new();
+    assert_eq!(MapLike::insert(&mut map, Key(1), 'a'), None);
+    assert_eq!(first_value(&map), Some('a'));
+    assert_eq!(MapLike::<_, _>::get(&map, &Key(1)), Some(&'a'));
+}